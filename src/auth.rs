@@ -0,0 +1,55 @@
+use serde_json;
+
+use ::error::*;
+use ::Dropbox;
+use ::models::auth::*;
+use ::models::error::*;
+
+pub struct DropboxAuth<'a>
+{
+	dropbox: &'a Dropbox,
+}
+
+impl<'a> DropboxAuth<'a>
+{
+	pub fn new(dropbox: &'a Dropbox)
+	-> DropboxAuth<'a>
+	{
+		DropboxAuth
+		{
+			dropbox: dropbox,
+		}
+	}
+
+	/// Creates an OAuth 2.0 access token from the supplied OAuth 1.0 access token.
+	/// The request is authenticated with the app key and secret of the credential.
+	pub fn token_from_oauth1(&self, arg: TokenFromOAuth1Arg)
+	-> Result<TokenFromOAuth1Result>
+	{
+		let uri = gen_uri!("auth", "token", "from_oauth1");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_app_request(&uri, &body)?;
+		match serde_json::from_str::<TokenFromOAuth1Result>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<TokenFromOAuth1Error>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::TokenFromOAuth1Error(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Disables the access token used to authenticate the call.
+	pub fn token_revoke(&self)
+	-> Result<()>
+	{
+		let uri = gen_uri!("auth", "token", "revoke");
+		let resp: String = self.dropbox.send_request(&uri, "")?;
+		match resp.trim()
+		{
+			"" | "null" => Ok(()),
+			_ => Err(DropboxError::Other),
+		}
+	}
+}
@@ -0,0 +1,259 @@
+use std::io::ErrorKind;
+use std::io::Error as StdIoError;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use hyper;
+use hyper::Client;
+use hyper::client::ProxyConfig;
+use hyper::net::{HttpConnector, HttpStream, HttpsConnector, NetworkConnector};
+use hyper_native_tls::NativeTlsClient;
+use hyper_native_tls::native_tls::{Certificate, TlsConnector};
+use serde_json;
+
+use ::error::*;
+use ::models::auth::*;
+use ::models::common::*;
+use ::secret::Secret;
+use ::Dropbox;
+use ::BASE_URL;
+use ::UPLOAD_URL;
+use ::USER_AGENT;
+
+/// How requests are retried when Dropbox answers with 429 Too Many Requests or refuses the connection.
+/// Requests answered with a 5xx status or failing after the connection was made aren't retried,
+/// as most routes aren't idempotent.
+/// By default requests aren't retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy
+{
+	/// The number of retries after the first attempt.
+	pub max_retries: u32,
+	/// The delay before the first retry, which doubles with every further retry.
+	/// A Retry-After header sent with a 429 takes precedence.
+	pub backoff: Duration,
+}
+
+impl Default for RetryPolicy
+{
+	fn default()
+	-> RetryPolicy
+	{
+		RetryPolicy
+		{
+			max_retries: 0,
+			backoff: Duration::from_secs(1),
+		}
+	}
+}
+
+impl RetryPolicy
+{
+	pub(crate) fn delay(&self, attempt: u32)
+	-> Duration
+	{
+		self.backoff * 2u32.saturating_pow(attempt)
+	}
+}
+
+/// The hosts the requests are sent to, e.g. to go through a gateway or to test against a mock server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiHosts
+{
+	/// The host of the rpc routes, https://api.dropboxapi.com by default.
+	pub api: String,
+	/// The host of the upload and download routes, https://content.dropboxapi.com by default.
+	pub content: String,
+}
+
+impl Default for ApiHosts
+{
+	fn default()
+	-> ApiHosts
+	{
+		ApiHosts
+		{
+			api: BASE_URL.to_owned(),
+			content: UPLOAD_URL.to_owned(),
+		}
+	}
+}
+
+/// Configures a Dropbox client. `Dropbox::new(token)` is the same as `DropboxBuilder::new(token).build()`.
+pub struct DropboxBuilder
+{
+	credential: Credential,
+	user_agent_suffix: Option<String>,
+	connect_timeout: Option<Duration>,
+	read_timeout: Option<Duration>,
+	proxy: Option<(String, u16)>,
+	root_certificates: Vec<Vec<u8>>,
+	retry_policy: RetryPolicy,
+	hosts: ApiHosts,
+	path_root: Option<PathRoot>,
+}
+
+impl DropboxBuilder
+{
+	pub fn new(token: String)
+	-> DropboxBuilder
+	{
+		DropboxBuilder::with_credential(Credential
+		{
+			access_token: Secret::new(token),
+			..Credential::default()
+		})
+	}
+
+	/// Creates a builder for a credential. See `Dropbox::with_credential`.
+	pub fn with_credential(credential: Credential)
+	-> DropboxBuilder
+	{
+		DropboxBuilder
+		{
+			credential: credential,
+			user_agent_suffix: None,
+			connect_timeout: None,
+			read_timeout: None,
+			proxy: None,
+			root_certificates: vec![],
+			retry_policy: RetryPolicy::default(),
+			hosts: ApiHosts::default(),
+			path_root: None,
+		}
+	}
+
+	/// Appends a suffix, e.g. the name and version of the app, to the User-Agent header.
+	pub fn user_agent_suffix(mut self, suffix: String)
+	-> DropboxBuilder
+	{
+		self.user_agent_suffix = Some(suffix);
+		self
+	}
+
+	/// Sets the timeout for establishing the connection.
+	pub fn connect_timeout(mut self, timeout: Duration)
+	-> DropboxBuilder
+	{
+		self.connect_timeout = Some(timeout);
+		self
+	}
+
+	/// Sets the timeout for reading the response.
+	pub fn read_timeout(mut self, timeout: Duration)
+	-> DropboxBuilder
+	{
+		self.read_timeout = Some(timeout);
+		self
+	}
+
+	/// Sends all requests through an HTTP proxy. Https requests are tunneled with CONNECT.
+	pub fn proxy(mut self, host: String, port: u16)
+	-> DropboxBuilder
+	{
+		self.proxy = Some((host, port));
+		self
+	}
+
+	/// Trusts a PEM encoded root certificate in addition to the system's root certificates.
+	/// Can be called multiple times to add a bundle.
+	pub fn root_certificate(mut self, pem: Vec<u8>)
+	-> DropboxBuilder
+	{
+		self.root_certificates.push(pem);
+		self
+	}
+
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy)
+	-> DropboxBuilder
+	{
+		self.retry_policy = retry_policy;
+		self
+	}
+
+	pub fn hosts(mut self, hosts: ApiHosts)
+	-> DropboxBuilder
+	{
+		self.hosts = hosts;
+		self
+	}
+
+	/// Sets the Dropbox-API-Path-Root header of all requests.
+	pub fn path_root(mut self, path_root: PathRoot)
+	-> DropboxBuilder
+	{
+		self.path_root = Some(path_root);
+		self
+	}
+
+	pub fn build(self)
+	-> Result<Dropbox>
+	{
+		let mut tls = TlsConnector::builder();
+		for pem in &self.root_certificates
+		{
+			tls.add_root_certificate(Certificate::from_pem(pem)?);
+		}
+		let ssl = NativeTlsClient::from(tls.build()?);
+		let connector = TimeoutConnector { timeout: self.connect_timeout };
+		let mut client = match self.proxy
+		{
+			None => Client::with_connector(HttpsConnector::with_connector(ssl, connector)),
+			Some((host, port)) => Client::with_proxy_config(ProxyConfig::new("http", host, port, connector, ssl)),
+		};
+		client.set_read_timeout(self.read_timeout);
+		let user_agent = match self.user_agent_suffix
+		{
+			None => USER_AGENT.to_owned(),
+			Some(suffix) => format!("{} {}", USER_AGENT, suffix),
+		};
+		let path_root = match self.path_root
+		{
+			None => None,
+			Some(ref path_root) => Some(serde_json::to_string(path_root)?),
+		};
+		Ok(Dropbox
+		{
+			client: client,
+			credential: Mutex::new(self.credential),
+			on_token_refresh: None,
+			user_agent: user_agent,
+			retry_policy: self.retry_policy,
+			hosts: self.hosts,
+			path_root: path_root,
+		})
+	}
+}
+
+/// Opens plain tcp connections like hyper's HttpConnector, but with a connect timeout.
+#[derive(Clone)]
+struct TimeoutConnector
+{
+	timeout: Option<Duration>,
+}
+
+impl NetworkConnector for TimeoutConnector
+{
+	type Stream = HttpStream;
+
+	fn connect(&self, host: &str, port: u16, scheme: &str)
+	-> hyper::Result<HttpStream>
+	{
+		let timeout = match self.timeout
+		{
+			None => return HttpConnector.connect(host, port, scheme),
+			Some(r) => r,
+		};
+		let mut last_err = StdIoError::new(ErrorKind::NotFound, "host resolved to no address");
+		for addr in (host, port).to_socket_addrs()?
+		{
+			match TcpStream::connect_timeout(&addr, timeout)
+			{
+				Err(e) => last_err = e,
+				Ok(r) => return Ok(HttpStream(r)),
+			}
+		}
+		Err(last_err.into())
+	}
+}
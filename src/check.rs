@@ -0,0 +1,54 @@
+use serde_json;
+
+use ::error::*;
+use ::Dropbox;
+use ::models::check::*;
+
+pub struct DropboxCheck<'a>
+{
+	dropbox: &'a Dropbox,
+}
+
+impl<'a> DropboxCheck<'a>
+{
+	pub fn new(dropbox: &'a Dropbox)
+	-> DropboxCheck<'a>
+	{
+		DropboxCheck
+		{
+			dropbox: dropbox,
+		}
+	}
+
+	/// This endpoint performs App Authentication, validating the supplied app key and secret,
+	/// and returns the supplied string, to allow you to test your code and connection to the Dropbox API.
+	/// It has no other effect.
+	pub fn app(&self, arg: EchoArg)
+	-> Result<EchoResult>
+	{
+		let uri = gen_uri!("check", "app");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_app_request(&uri, &body)?;
+		match serde_json::from_str::<EchoResult>(&resp)
+		{
+			Err(_) => Err(DropboxError::Other),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// This endpoint performs User Authentication, validating the supplied access token,
+	/// and returns the supplied string, to allow you to test your code and connection to the Dropbox API.
+	/// It has no other effect.
+	pub fn user(&self, arg: EchoArg)
+	-> Result<EchoResult>
+	{
+		let uri = gen_uri!("check", "user");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<EchoResult>(&resp)
+		{
+			Err(_) => Err(DropboxError::Other),
+			Ok(r) => Ok(r),
+		}
+	}
+}
@@ -0,0 +1,60 @@
+use std::vec::IntoIter;
+
+use ::error::*;
+
+type NextPage<'a, T> = Box<dyn Fn(String) -> Result<(Vec<T>, Option<String>)> + 'a>;
+
+/// Iterator over the entries of a route that pages its results with a cursor.
+/// The next page is only requested once every entry of the current page has been returned.
+/// If requesting a page fails, the error is returned once and the iteration ends.
+pub struct CursorIter<'a, T>
+{
+	entries: IntoIter<T>,
+	cursor: Option<String>,
+	next_page: NextPage<'a, T>,
+}
+
+impl<'a, T> CursorIter<'a, T>
+{
+	/// Creates a new iterator from the first page of results.
+	/// `next_page` is called with the last cursor as long as one is present
+	/// and returns the entries of the following page together with the cursor to continue from.
+	pub(crate) fn new<F>(entries: Vec<T>, cursor: Option<String>, next_page: F)
+	-> CursorIter<'a, T>
+		where F: Fn(String) -> Result<(Vec<T>, Option<String>)> + 'a
+	{
+		CursorIter
+		{
+			entries: entries.into_iter(),
+			cursor: cursor,
+			next_page: Box::new(next_page),
+		}
+	}
+}
+
+impl<'a, T> Iterator for CursorIter<'a, T>
+{
+	type Item = Result<T>;
+
+	fn next(&mut self)
+	-> Option<Result<T>>
+	{
+		loop
+		{
+			if let Some(entry) = self.entries.next()
+			{
+				return Some(Ok(entry));
+			}
+			let cursor = self.cursor.take()?;
+			match (self.next_page)(cursor)
+			{
+				Err(e) => return Some(Err(e)),
+				Ok((entries, cursor)) =>
+				{
+					self.entries = entries.into_iter();
+					self.cursor = cursor;
+				},
+			}
+		}
+	}
+}
@@ -4,6 +4,7 @@ use std::io::Error as StdIoError;
 // extern uses
 use hyper::error::Error as HyperErr;
 use serde_json::Error as SerdeJsonError;
+use serde_urlencoded::ser::Error as UrlEncodeError;
 use hyper_native_tls::native_tls::Error as TlsError;
 // intern uses
 use ::models::error::*;
@@ -17,10 +18,29 @@ pub enum DropboxError
 	Utf8Error(FromUtf8Error),
 	IoError(StdIoError),
 	JsonError(SerdeJsonError),
+	UrlEncodeError(UrlEncodeError),
 	MissingDropboxApiResult,
+	/// The requested revision is not part of the file's history.
+	MissingRevision,
 	ServerError(TlsError),
+	/// The state returned to the redirect uri doesn't match the state of the OAuth2 flow.
+	StateMismatch,
+	/// The access token can't be refreshed, because the credential has no refresh token.
+	MissingRefreshToken,
+	/// The access token can't be refreshed, because the credential has no app key.
+	MissingAppKey,
+	/// The request needs app authentication, but the credential has no app secret.
+	MissingAppSecret,
+	/// The credential store has no credential for the account.
+	MissingCredential,
+	OAuth2Error(OAuth2Error),
 
 	// Dropbox api errors
+	/// The access token was rejected. Returned by every route.
+	Auth(Error<AuthError>),
+	/// The Dropbox-API-Path-Root header was rejected. Returned by every route.
+	PathRootError(Error<PathRootError>),
+	TokenFromOAuth1Error(Error<TokenFromOAuth1Error>),
 	RelocationError(Error<RelocationError>),
 	PollError(Error<PollError>),
 	GetCopyReferenceError(Error<GetCopyReferenceError>),
@@ -36,14 +56,56 @@ pub enum DropboxError
 	ListFolderContinueError(Error<ListFolderContinueError>),
 	ListFolderLongpollError(Error<ListFolderLongpollError>),
 	ListRevisionsError(Error<ListRevisionsError>),
+	PropertiesSearchError(Error<PropertiesSearchError>),
+	PropertiesSearchContinueError(Error<PropertiesSearchContinueError>),
 	RestoreError(Error<RestoreError>),
 	SaveUrlError(Error<SaveUrlError>),
 	SearchError(Error<SearchError>),
+	AddTagError(Error<AddTagError>),
+	BaseTagError(Error<BaseTagError>),
+	RemoveTagError(Error<RemoveTagError>),
 	UploadError(Error<UploadError>),
 	UploadSessionLookupError(Error<UploadSessionLookupError>),
 	UploadSessionFinishError(Error<UploadSessionFinishError>),
 	GetAccountError(Error<GetAccountError>),
 	GetAccountBatchError(Error<GetAccountBatchError>),
+	/// Boxed, because the error can carry the metadata of an existing shared link.
+	CreateSharedLinkWithSettingsError(Box<Error<CreateSharedLinkWithSettingsError>>),
+	ListSharedLinksError(Error<ListSharedLinksError>),
+	ModifySharedLinkSettingsError(Error<ModifySharedLinkSettingsError>),
+	RevokeSharedLinkError(Error<RevokeSharedLinkError>),
+	SharedLinkError(Error<SharedLinkError>),
+	GetSharedLinkFileError(Error<GetSharedLinkFileError>),
+	/// Boxed, because the error can carry the metadata of an already shared folder.
+	ShareFolderError(Box<Error<ShareFolderError>>),
+	UnshareFolderError(Error<UnshareFolderError>),
+	AddFolderMemberError(Error<AddFolderMemberError>),
+	RemoveFolderMemberError(Error<RemoveFolderMemberError>),
+	UpdateFolderMemberError(Error<UpdateFolderMemberError>),
+	SharedFolderAccessError(Error<SharedFolderAccessError>),
+	ListFolderMembersContinueError(Error<ListFolderMembersContinueError>),
+	SharingUserError(Error<SharingUserError>),
+	AddFileMemberError(Error<AddFileMemberError>),
+	RemoveFileMemberError(Error<RemoveFileMemberError>),
+	FileMemberActionError(Error<FileMemberActionError>),
+	ListFileMembersError(Error<ListFileMembersError>),
+	ListFileMembersContinueError(Error<ListFileMembersContinueError>),
+	ListFilesContinueError(Error<ListFilesContinueError>),
+	ListFoldersContinueError(Error<ListFoldersContinueError>),
+	MountFolderError(Error<MountFolderError>),
+	UnmountFolderError(Error<UnmountFolderError>),
+	RelinquishFolderMembershipError(Error<RelinquishFolderMembershipError>),
+	TransferFolderError(Error<TransferFolderError>),
+	ListDocsCursorError(Error<ListDocsCursorError>),
+	DocLookupError(Error<DocLookupError>),
+	PaperDocCreateError(Error<PaperDocCreateError>),
+	PaperDocUpdateError(Error<PaperDocUpdateError>),
+	ListUsersCursorError(Error<ListUsersCursorError>),
+	GeneralFileRequestsError(Error<GeneralFileRequestsError>),
+	ListFileRequestsContinueError(Error<ListFileRequestsContinueError>),
+	FileRequestError(Error<FileRequestError>),
+	CreateFileRequestError(Error<CreateFileRequestError>),
+	DeleteFileRequestError(Error<DeleteFileRequestError>),
 
 	Other,
 }
@@ -84,6 +146,24 @@ impl From<SerdeJsonError> for DropboxError
 	}
 }
 
+impl From<UrlEncodeError> for DropboxError
+{
+	fn from(err: UrlEncodeError)
+	-> DropboxError
+	{
+		DropboxError::UrlEncodeError(err)
+	}
+}
+
+impl From<OAuth2Error> for DropboxError
+{
+	fn from(err: OAuth2Error)
+	-> DropboxError
+	{
+		DropboxError::OAuth2Error(err)
+	}
+}
+
 impl From<TlsError> for DropboxError
 {
 	fn from(err: TlsError)
@@ -93,6 +173,33 @@ impl From<TlsError> for DropboxError
 	}
 }
 
+impl From<Error<AuthError>> for DropboxError
+{
+	fn from(err: Error<AuthError>)
+	-> DropboxError
+	{
+		DropboxError::Auth(err)
+	}
+}
+
+impl From<Error<PathRootError>> for DropboxError
+{
+	fn from(err: Error<PathRootError>)
+	-> DropboxError
+	{
+		DropboxError::PathRootError(err)
+	}
+}
+
+impl From<Error<TokenFromOAuth1Error>> for DropboxError
+{
+	fn from(err: Error<TokenFromOAuth1Error>)
+	-> DropboxError
+	{
+		DropboxError::TokenFromOAuth1Error(err)
+	}
+}
+
 impl From<Error<RelocationError>> for DropboxError
 {
 	fn from(err: Error<RelocationError>)
@@ -228,6 +335,24 @@ impl From<Error<ListRevisionsError>> for DropboxError
 	}
 }
 
+impl From<Error<PropertiesSearchError>> for DropboxError
+{
+	fn from(err: Error<PropertiesSearchError>)
+	-> DropboxError
+	{
+		DropboxError::PropertiesSearchError(err)
+	}
+}
+
+impl From<Error<PropertiesSearchContinueError>> for DropboxError
+{
+	fn from(err: Error<PropertiesSearchContinueError>)
+	-> DropboxError
+	{
+		DropboxError::PropertiesSearchContinueError(err)
+	}
+}
+
 impl From<Error<RestoreError>> for DropboxError
 {
 	fn from(err: Error<RestoreError>)
@@ -255,6 +380,32 @@ impl From<Error<SearchError>> for DropboxError
 	}
 }
 
+impl From<Error<AddTagError>> for DropboxError
+{
+	fn from(err: Error<AddTagError>)
+	-> DropboxError
+	{
+		DropboxError::AddTagError(err)
+	}
+}
+
+impl From<Error<BaseTagError>> for DropboxError
+{
+	fn from(err: Error<BaseTagError>)
+	-> DropboxError
+	{
+		DropboxError::BaseTagError(err)
+	}
+}
+
+impl From<Error<RemoveTagError>> for DropboxError
+{
+	fn from(err: Error<RemoveTagError>)
+	-> DropboxError
+	{
+		DropboxError::RemoveTagError(err)
+	}
+}
 
 impl From<Error<UploadError>> for DropboxError
 {
@@ -300,3 +451,318 @@ impl From<Error<GetAccountBatchError>> for DropboxError
 		DropboxError::GetAccountBatchError(err)
 	}
 }
+
+impl From<Error<CreateSharedLinkWithSettingsError>> for DropboxError
+{
+	fn from(err: Error<CreateSharedLinkWithSettingsError>)
+	-> DropboxError
+	{
+		DropboxError::CreateSharedLinkWithSettingsError(Box::new(err))
+	}
+}
+
+impl From<Error<ListSharedLinksError>> for DropboxError
+{
+	fn from(err: Error<ListSharedLinksError>)
+	-> DropboxError
+	{
+		DropboxError::ListSharedLinksError(err)
+	}
+}
+
+impl From<Error<ModifySharedLinkSettingsError>> for DropboxError
+{
+	fn from(err: Error<ModifySharedLinkSettingsError>)
+	-> DropboxError
+	{
+		DropboxError::ModifySharedLinkSettingsError(err)
+	}
+}
+
+impl From<Error<RevokeSharedLinkError>> for DropboxError
+{
+	fn from(err: Error<RevokeSharedLinkError>)
+	-> DropboxError
+	{
+		DropboxError::RevokeSharedLinkError(err)
+	}
+}
+
+impl From<Error<SharedLinkError>> for DropboxError
+{
+	fn from(err: Error<SharedLinkError>)
+	-> DropboxError
+	{
+		DropboxError::SharedLinkError(err)
+	}
+}
+
+impl From<Error<GetSharedLinkFileError>> for DropboxError
+{
+	fn from(err: Error<GetSharedLinkFileError>)
+	-> DropboxError
+	{
+		DropboxError::GetSharedLinkFileError(err)
+	}
+}
+
+impl From<Error<ShareFolderError>> for DropboxError
+{
+	fn from(err: Error<ShareFolderError>)
+	-> DropboxError
+	{
+		DropboxError::ShareFolderError(Box::new(err))
+	}
+}
+
+impl From<Error<UnshareFolderError>> for DropboxError
+{
+	fn from(err: Error<UnshareFolderError>)
+	-> DropboxError
+	{
+		DropboxError::UnshareFolderError(err)
+	}
+}
+
+impl From<Error<AddFolderMemberError>> for DropboxError
+{
+	fn from(err: Error<AddFolderMemberError>)
+	-> DropboxError
+	{
+		DropboxError::AddFolderMemberError(err)
+	}
+}
+
+impl From<Error<RemoveFolderMemberError>> for DropboxError
+{
+	fn from(err: Error<RemoveFolderMemberError>)
+	-> DropboxError
+	{
+		DropboxError::RemoveFolderMemberError(err)
+	}
+}
+
+impl From<Error<UpdateFolderMemberError>> for DropboxError
+{
+	fn from(err: Error<UpdateFolderMemberError>)
+	-> DropboxError
+	{
+		DropboxError::UpdateFolderMemberError(err)
+	}
+}
+
+impl From<Error<SharedFolderAccessError>> for DropboxError
+{
+	fn from(err: Error<SharedFolderAccessError>)
+	-> DropboxError
+	{
+		DropboxError::SharedFolderAccessError(err)
+	}
+}
+
+impl From<Error<ListFolderMembersContinueError>> for DropboxError
+{
+	fn from(err: Error<ListFolderMembersContinueError>)
+	-> DropboxError
+	{
+		DropboxError::ListFolderMembersContinueError(err)
+	}
+}
+
+impl From<Error<SharingUserError>> for DropboxError
+{
+	fn from(err: Error<SharingUserError>)
+	-> DropboxError
+	{
+		DropboxError::SharingUserError(err)
+	}
+}
+
+impl From<Error<AddFileMemberError>> for DropboxError
+{
+	fn from(err: Error<AddFileMemberError>)
+	-> DropboxError
+	{
+		DropboxError::AddFileMemberError(err)
+	}
+}
+
+impl From<Error<RemoveFileMemberError>> for DropboxError
+{
+	fn from(err: Error<RemoveFileMemberError>)
+	-> DropboxError
+	{
+		DropboxError::RemoveFileMemberError(err)
+	}
+}
+
+impl From<Error<FileMemberActionError>> for DropboxError
+{
+	fn from(err: Error<FileMemberActionError>)
+	-> DropboxError
+	{
+		DropboxError::FileMemberActionError(err)
+	}
+}
+
+impl From<Error<ListFileMembersError>> for DropboxError
+{
+	fn from(err: Error<ListFileMembersError>)
+	-> DropboxError
+	{
+		DropboxError::ListFileMembersError(err)
+	}
+}
+
+impl From<Error<ListFileMembersContinueError>> for DropboxError
+{
+	fn from(err: Error<ListFileMembersContinueError>)
+	-> DropboxError
+	{
+		DropboxError::ListFileMembersContinueError(err)
+	}
+}
+
+impl From<Error<ListFilesContinueError>> for DropboxError
+{
+	fn from(err: Error<ListFilesContinueError>)
+	-> DropboxError
+	{
+		DropboxError::ListFilesContinueError(err)
+	}
+}
+
+impl From<Error<ListFoldersContinueError>> for DropboxError
+{
+	fn from(err: Error<ListFoldersContinueError>)
+	-> DropboxError
+	{
+		DropboxError::ListFoldersContinueError(err)
+	}
+}
+
+impl From<Error<MountFolderError>> for DropboxError
+{
+	fn from(err: Error<MountFolderError>)
+	-> DropboxError
+	{
+		DropboxError::MountFolderError(err)
+	}
+}
+
+impl From<Error<UnmountFolderError>> for DropboxError
+{
+	fn from(err: Error<UnmountFolderError>)
+	-> DropboxError
+	{
+		DropboxError::UnmountFolderError(err)
+	}
+}
+
+impl From<Error<RelinquishFolderMembershipError>> for DropboxError
+{
+	fn from(err: Error<RelinquishFolderMembershipError>)
+	-> DropboxError
+	{
+		DropboxError::RelinquishFolderMembershipError(err)
+	}
+}
+
+impl From<Error<TransferFolderError>> for DropboxError
+{
+	fn from(err: Error<TransferFolderError>)
+	-> DropboxError
+	{
+		DropboxError::TransferFolderError(err)
+	}
+}
+
+impl From<Error<ListDocsCursorError>> for DropboxError
+{
+	fn from(err: Error<ListDocsCursorError>)
+	-> DropboxError
+	{
+		DropboxError::ListDocsCursorError(err)
+	}
+}
+
+impl From<Error<DocLookupError>> for DropboxError
+{
+	fn from(err: Error<DocLookupError>)
+	-> DropboxError
+	{
+		DropboxError::DocLookupError(err)
+	}
+}
+
+impl From<Error<PaperDocCreateError>> for DropboxError
+{
+	fn from(err: Error<PaperDocCreateError>)
+	-> DropboxError
+	{
+		DropboxError::PaperDocCreateError(err)
+	}
+}
+
+impl From<Error<PaperDocUpdateError>> for DropboxError
+{
+	fn from(err: Error<PaperDocUpdateError>)
+	-> DropboxError
+	{
+		DropboxError::PaperDocUpdateError(err)
+	}
+}
+
+impl From<Error<ListUsersCursorError>> for DropboxError
+{
+	fn from(err: Error<ListUsersCursorError>)
+	-> DropboxError
+	{
+		DropboxError::ListUsersCursorError(err)
+	}
+}
+
+impl From<Error<GeneralFileRequestsError>> for DropboxError
+{
+	fn from(err: Error<GeneralFileRequestsError>)
+	-> DropboxError
+	{
+		DropboxError::GeneralFileRequestsError(err)
+	}
+}
+
+impl From<Error<ListFileRequestsContinueError>> for DropboxError
+{
+	fn from(err: Error<ListFileRequestsContinueError>)
+	-> DropboxError
+	{
+		DropboxError::ListFileRequestsContinueError(err)
+	}
+}
+
+impl From<Error<FileRequestError>> for DropboxError
+{
+	fn from(err: Error<FileRequestError>)
+	-> DropboxError
+	{
+		DropboxError::FileRequestError(err)
+	}
+}
+
+impl From<Error<CreateFileRequestError>> for DropboxError
+{
+	fn from(err: Error<CreateFileRequestError>)
+	-> DropboxError
+	{
+		DropboxError::CreateFileRequestError(err)
+	}
+}
+
+impl From<Error<DeleteFileRequestError>> for DropboxError
+{
+	fn from(err: Error<DeleteFileRequestError>)
+	-> DropboxError
+	{
+		DropboxError::DeleteFileRequestError(err)
+	}
+}
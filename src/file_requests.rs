@@ -0,0 +1,186 @@
+use serde_json;
+
+use ::cursor::CursorIter;
+use ::error::*;
+use ::Dropbox;
+use ::models::file_requests::*;
+use ::models::error::*;
+
+pub struct DropboxFileRequests<'a>
+{
+	dropbox: &'a Dropbox,
+}
+
+impl<'a> DropboxFileRequests<'a>
+{
+	pub fn new(dropbox: &'a Dropbox)
+	-> DropboxFileRequests<'a>
+	{
+		DropboxFileRequests
+		{
+			dropbox: dropbox,
+		}
+	}
+
+	/// Returns the total number of file requests owned by this user. Includes both open and closed file requests.
+	pub fn count(&self)
+	-> Result<CountFileRequestsResult>
+	{
+		let uri = gen_uri!("file_requests", "count");
+		let resp: String = self.dropbox.send_request(&uri, "")?;
+		match serde_json::from_str::<CountFileRequestsResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<GeneralFileRequestsError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::GeneralFileRequestsError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Creates a file request for this user.
+	pub fn create(&self, arg: CreateFileRequestArgs)
+	-> Result<FileRequest>
+	{
+		let uri = gen_uri!("file_requests", "create");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<FileRequest>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<CreateFileRequestError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::CreateFileRequestError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Delete a batch of closed file requests.
+	pub fn delete(&self, arg: DeleteFileRequestArgs)
+	-> Result<DeleteFileRequestsResult>
+	{
+		let uri = gen_uri!("file_requests", "delete");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<DeleteFileRequestsResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DeleteFileRequestError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DeleteFileRequestError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Delete all closed file requests owned by this user.
+	pub fn delete_all_closed(&self)
+	-> Result<DeleteAllClosedFileRequestsResult>
+	{
+		let uri = gen_uri!("file_requests", "delete_all_closed");
+		let resp: String = self.dropbox.send_request(&uri, "")?;
+		match serde_json::from_str::<DeleteAllClosedFileRequestsResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<FileRequestError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::FileRequestError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns the specified file request.
+	pub fn get(&self, arg: GetFileRequestArgs)
+	-> Result<FileRequest>
+	{
+		let uri = gen_uri!("file_requests", "get");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<FileRequest>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<FileRequestError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::FileRequestError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns a list of file requests owned by this user. For apps with the app folder permission,
+	/// this will only return file requests with destinations in the app folder.
+	pub fn list_v2(&self, arg: ListFileRequestsArg)
+	-> Result<ListFileRequestsV2Result>
+	{
+		let uri = gen_uri!("file_requests", "list_v2");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFileRequestsV2Result>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<GeneralFileRequestsError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::GeneralFileRequestsError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from list_v2, use this to paginate through all file requests.
+	/// The cursor must come from a previous call to list_v2 or list_continue.
+	pub fn list_continue(&self, arg: ListFileRequestsContinueArg)
+	-> Result<ListFileRequestsV2Result>
+	{
+		let uri = gen_uri!("file_requests", "list", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFileRequestsV2Result>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFileRequestsContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFileRequestsContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over every file request returned by list_v2 and list_continue.
+	pub fn list_iter(&self, arg: ListFileRequestsArg)
+	-> Result<CursorIter<'a, FileRequest>>
+	{
+		let first = self.list_v2(arg)?;
+		let cursor = if first.has_more { Some(first.cursor) } else { None };
+		let file_requests = DropboxFileRequests::new(self.dropbox);
+		Ok(CursorIter::new(first.file_requests, cursor, move |cursor|
+		{
+			let page = file_requests.list_continue(ListFileRequestsContinueArg
+			{
+				cursor: cursor,
+			})?;
+			let cursor = if page.has_more { Some(page.cursor) } else { None };
+			Ok((page.file_requests, cursor))
+		}))
+	}
+
+	/// Update a file request.
+	pub fn update(&self, arg: UpdateFileRequestArgs)
+	-> Result<FileRequest>
+	{
+		let uri = gen_uri!("file_requests", "update");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<FileRequest>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<FileRequestError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::FileRequestError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+}
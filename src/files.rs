@@ -1,12 +1,17 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use serde_json;
 
 use ::error::*;
 use ::Dropbox;
+use ::cursor::CursorIter;
 use ::models::files::*;
 use ::models::error::*;
 
+/// Number of deleted entries purge_deleted processes before reporting its progress.
+pub const PURGE_BATCH_SIZE: usize = 100;
+
 pub struct DropboxFiles<'a>
 {
 	dropbox: &'a Dropbox,
@@ -217,6 +222,57 @@ impl<'a> DropboxFiles<'a>
 		}
 	}
 
+	/// Compares the metadata of two revisions of a file and returns every field which differs.
+	pub fn diff_metadata(&self, rev_a: &str, rev_b: &str)
+	-> Result<MetadataDiff>
+	{
+		let from = self.get_revision_metadata(rev_a)?;
+		let to = self.get_revision_metadata(rev_b)?;
+		let mut changes = vec![];
+		if from.path_lower != to.path_lower
+		{
+			changes.push(MetadataChange::Path
+			{
+				from: from.path_display.clone(),
+				to: to.path_display.clone(),
+			});
+		}
+		if from.size != to.size
+		{
+			changes.push(MetadataChange::Size { from: from.size, to: to.size });
+		}
+		if from.content_hash != to.content_hash
+		{
+			changes.push(MetadataChange::Content
+			{
+				from: from.content_hash.clone(),
+				to: to.content_hash.clone(),
+			});
+		}
+		if from.client_modified != to.client_modified
+		{
+			changes.push(MetadataChange::ClientModified
+			{
+				from: from.client_modified.clone(),
+				to: to.client_modified.clone(),
+			});
+		}
+		if from.server_modified != to.server_modified
+		{
+			changes.push(MetadataChange::ServerModified
+			{
+				from: from.server_modified.clone(),
+				to: to.server_modified.clone(),
+			});
+		}
+		Ok(MetadataDiff
+		{
+			from: from,
+			to: to,
+			changes: changes,
+		})
+	}
+
 	/// Download a file from a user's Dropbox.
 	pub fn download(&self, arg: DownloadArg, file_path: &Path)
 	-> Result<FileMetadata>
@@ -320,6 +376,28 @@ impl<'a> DropboxFiles<'a>
 		Ok(file_info)
 	}
 
+	/// Returns up to `limit` revisions of a file with its newest revision first.
+	/// Dropbox returns at most 100 revisions per request.
+	/// With ListRevisionsMode::Id the revisions of the file are followed even if it has been moved or renamed.
+	pub fn history(&self, path: &str, mode: ListRevisionsMode, limit: u64)
+	-> Result<FileHistory>
+	{
+		let result = self.list_revisions(ListRevisionsArg
+		{
+			path: path.to_owned(),
+			mode: mode,
+			limit: limit,
+		})?;
+		let mut revisions = result.entries;
+		revisions.sort_by(|a, b| b.server_modified.cmp(&a.server_modified));
+		Ok(FileHistory
+		{
+			is_deleted: result.is_deleted,
+			server_deleted: result.server_deleted,
+			revisions: revisions,
+		})
+	}
+
 	/// Starts returning the contents of a folder.
 	/// If the result's ListFolderResult.has_more field is true,
 	/// call list_folder/continue with the returned ListFolderResult.cursor to retrieve more entries.
@@ -360,7 +438,7 @@ impl<'a> DropboxFiles<'a>
 	pub fn list_folders_continue(&self, arg: ListFolderContinueArg)
 	-> Result<ListFolderResult>
 	{
-		let uri = gen_uri!("files", "list_folders", "continue");
+		let uri = gen_uri!("files", "list_folder", "continue");
 		let body: String = serde_json::to_string(&arg)?;
 		let resp: String = self.dropbox.send_request(&uri, &body)?;
 		match serde_json::from_str::<ListFolderResult>(&resp)
@@ -374,6 +452,22 @@ impl<'a> DropboxFiles<'a>
 		}
 	}
 
+	/// Iterates over every entry of list_folder,
+	/// calling list_folder/continue whenever a page has been consumed.
+	pub fn list_folder_iter(&self, arg: ListFolderArg)
+	-> Result<CursorIter<'a, Metadata>>
+	{
+		let first = self.list_folder(arg)?;
+		let cursor = if first.has_more { Some(first.cursor) } else { None };
+		let files = DropboxFiles::new(self.dropbox);
+		Ok(CursorIter::new(first.entries, cursor, move |cursor|
+		{
+			let page = files.list_folders_continue(ListFolderContinueArg { cursor: cursor })?;
+			let cursor = if page.has_more { Some(page.cursor) } else { None };
+			Ok((page.entries, cursor))
+		}))
+	}
+
 	/// A way to quickly get a cursor for the folder's state. Unlike list_folder,
 	/// list_folder/get_latest_cursor doesn't return any entries.
 	/// This endpoint is for app which only needs to know about new files and modifications
@@ -437,7 +531,6 @@ impl<'a> DropboxFiles<'a>
 
 	/// Move a file or folder to a different location in the user's Dropbox.
 	/// If the source path is a folder all its contents will be moved.
-	#[link_name="move"]
 	pub fn _move(&self, arg: RelocationArg)
 	-> Result<Metadata>
 	{
@@ -493,15 +586,15 @@ impl<'a> DropboxFiles<'a>
 
 	/// Permanently delete the file or folder at a given path (see https://www.dropbox.com/en/help/40).
 	/// Note: This endpoint is only available for Dropbox Business apps.
-	pub fn permanetly_delete(&self, arg: DeleteArg)
+	pub fn permanently_delete(&self, arg: DeleteArg)
 	-> Result<()>
 	{
-		let uri = gen_uri!("files", "permanetly_delete");
+		let uri = gen_uri!("files", "permanently_delete");
 		let body: String = serde_json::to_string(&arg)?;
 		let resp: String = self.dropbox.send_request(&uri, &body)?;
 		match serde_json::from_str::<Error<DeleteError>>(&resp)
 		{
-			Err(e) => match e.is_eof()
+			Err(e) => match e.is_eof() || resp.trim() == "null"
 			{
 				false => Err(DropboxError::Other),
 				true => Ok(()),
@@ -541,6 +634,83 @@ impl<'a> DropboxFiles<'a>
 		Err(DropboxError::Other)
 	}
 
+	/// Search across property templates for particular property field values.
+	pub fn properties_search(&self, arg: PropertiesSearchArg)
+	-> Result<PropertiesSearchResult>
+	{
+		let uri = gen_uri!("file_properties", "properties", "search");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<PropertiesSearchResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PropertiesSearchError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PropertiesSearchError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from properties/search,
+	/// use this to paginate through all search results.
+	pub fn properties_search_continue(&self, arg: PropertiesSearchContinueArg)
+	-> Result<PropertiesSearchResult>
+	{
+		let uri = gen_uri!("file_properties", "properties", "search", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<PropertiesSearchResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PropertiesSearchContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PropertiesSearchContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over every match of properties/search,
+	/// calling properties/search/continue whenever a page has been consumed.
+	pub fn properties_search_iter(&self, arg: PropertiesSearchArg)
+	-> Result<CursorIter<'a, PropertiesSearchMatch>>
+	{
+		let first = self.properties_search(arg)?;
+		let files = DropboxFiles::new(self.dropbox);
+		Ok(CursorIter::new(first.matches, first.cursor, move |cursor|
+		{
+			let page = files.properties_search_continue(PropertiesSearchContinueArg { cursor: cursor })?;
+			Ok((page.matches, page.cursor))
+		}))
+	}
+
+	/// Searches for property field values like properties/search and returns the metadata
+	/// of every matched file or folder together with its matching property groups.
+	/// This pages through all search results and requests the metadata of each match.
+	pub fn properties_search_metadata(&self, arg: PropertiesSearchArg)
+	-> Result<Vec<PropertiesSearchEntry>>
+	{
+		let mut entries = vec![];
+		for search_match in self.properties_search_iter(arg)?
+		{
+			let search_match = search_match?;
+			let metadata = self.get_metadata(GetMetadataArg
+			{
+				path: search_match.id,
+				include_media_info: false,
+				include_deleted: search_match.is_deleted,
+				include_has_explicit_shared_members: false,
+			})?;
+			entries.push(PropertiesSearchEntry
+			{
+				metadata: metadata,
+				property_groups: search_match.property_groups,
+			});
+		}
+		Ok(entries)
+	}
+
 	/// PREVIEW - may change or disappear without notice
 	///
 	/// Get the schema for a specified template.
@@ -570,6 +740,88 @@ impl<'a> DropboxFiles<'a>
 		Err(DropboxError::Other)
 	}
 
+	/// Permanently deletes every file below `path` which was deleted before `older_than`.
+	/// The timestamp has to be in the format Dropbox uses for server_modified, e.g. "2015-05-12T15:50:38Z".
+	/// Every entry is removed with its own permanently_delete call, `progress` is called with the
+	/// report so far after every PURGE_BATCH_SIZE entries. Deleted folders are skipped, because Dropbox
+	/// doesn't report when they were deleted. Entries which fail are recorded in the report and don't
+	/// stop the purge.
+	/// Note: This uses permanently_delete, which is only available for Dropbox Business apps.
+	pub fn purge_deleted<F>(&self, path: &str, older_than: &str, mut progress: F)
+	-> Result<PurgeReport>
+		where F: FnMut(&PurgeReport)
+	{
+		let mut deleted = vec![];
+		for entry in self.list_folder_iter(ListFolderArg
+		{
+			path: path.to_owned(),
+			recursive: true,
+			include_media_info: false,
+			include_deleted: true,
+			include_has_explicit_shared_members: false,
+			shared_link: None,
+		})?
+		{
+			if let Metadata::Deleted(entry) = entry?
+			{
+				if let Some(path) = entry.path_display
+				{
+					deleted.push(path);
+				}
+			}
+		}
+
+		let mut report = PurgeReport
+		{
+			total: deleted.len(),
+			..PurgeReport::default()
+		};
+		for batch in deleted.chunks(PURGE_BATCH_SIZE)
+		{
+			for path in batch
+			{
+				let history = match self.history(path, ListRevisionsMode::Path, 1)
+				{
+					Err(DropboxError::ListRevisionsError(ref e))
+						if e.error == ListRevisionsError::Path { path: LookupError::NotFile } =>
+					{
+						report.skipped.push(path.clone());
+						continue;
+					},
+					Err(e) =>
+					{
+						report.failed.push(PurgeFailure
+						{
+							path: path.clone(),
+							error: e,
+						});
+						continue;
+					},
+					Ok(r) => r,
+				};
+				let expired = history.is_deleted && history.server_deleted
+					.as_ref()
+					.is_some_and(|d| d.as_str() < older_than);
+				if !expired
+				{
+					report.skipped.push(path.clone());
+					continue;
+				}
+				match self.permanently_delete(DeleteArg { path: path.clone() })
+				{
+					Err(e) => report.failed.push(PurgeFailure
+					{
+						path: path.clone(),
+						error: e,
+					}),
+					Ok(()) => report.purged.push(path.clone()),
+				}
+			}
+			progress(&report);
+		}
+		Ok(report)
+	}
+
 	/// Restore a file to a specific revision.
 	pub fn restore(&self, arg: RestoreArg)
 	-> Result<FileMetadata>
@@ -588,6 +840,159 @@ impl<'a> DropboxFiles<'a>
 		}
 	}
 
+	/// Restores every file below `path` to the revision which was current at `timestamp`
+	/// and re-creates deleted folders that contain restored files.
+	/// The timestamp has to be in the format Dropbox uses for server_modified, e.g. "2015-05-12T15:50:38Z".
+	/// Files which did not exist at that time are left untouched. Only the newest 100 revisions of
+	/// a file are looked at, files with more revisions after the timestamp are reported as skipped.
+	/// If `dry_run` is true nothing is changed and the report only contains the planned actions.
+	pub fn restore_folder_at(&self, path: &str, timestamp: &str, dry_run: bool)
+	-> Result<FolderRestoreReport>
+	{
+		let mut current_revs = HashMap::new();
+		let mut candidates = vec![];
+		for entry in self.list_folder_iter(ListFolderArg
+		{
+			path: path.to_owned(),
+			recursive: true,
+			include_media_info: false,
+			include_deleted: true,
+			include_has_explicit_shared_members: false,
+			shared_link: None,
+		})?
+		{
+			match entry?
+			{
+				Metadata::File(file) => if let (Some(lower), Some(display)) = (file.path_lower, file.path_display)
+				{
+					current_revs.insert(lower.clone(), file.rev);
+					candidates.push((lower, display));
+				},
+				Metadata::Deleted(deleted) => if let (Some(lower), Some(display)) = (deleted.path_lower, deleted.path_display)
+				{
+					candidates.push((lower, display));
+				},
+				Metadata::Folder(_) => {},
+			}
+		}
+
+		let mut restores = vec![];
+		let mut deleted_folders = vec![];
+		let mut skipped = vec![];
+		for (lower, display) in candidates
+		{
+			let history = match self.history(&lower, ListRevisionsMode::Path, 100)
+			{
+				Err(DropboxError::ListRevisionsError(ref e))
+					if e.error == ListRevisionsError::Path { path: LookupError::NotFile } =>
+				{
+					deleted_folders.push((lower, display));
+					continue;
+				},
+				Err(e) => return Err(e),
+				Ok(r) => r,
+			};
+			if history.is_deleted && history.server_deleted.as_ref().is_some_and(|d| d.as_str() <= timestamp)
+			{
+				continue;
+			}
+			let complete = history.revisions.len() < 100;
+			let rev = match history.revisions.into_iter().find(|r| r.server_modified.as_str() <= timestamp)
+			{
+				None if complete => continue,
+				None =>
+				{
+					skipped.push(display);
+					continue;
+				},
+				Some(r) => r.rev,
+			};
+			if current_revs.get(&lower) == Some(&rev)
+			{
+				continue;
+			}
+			restores.push((lower, display, rev));
+		}
+
+		let mut actions = vec![];
+		deleted_folders.sort();
+		for (folder, display) in deleted_folders
+		{
+			let prefix = format!("{}/", folder);
+			if restores.iter().any(|(lower, _, _)| lower.starts_with(&prefix))
+			{
+				actions.push(FolderRestoreAction::CreateFolder { path: display });
+			}
+		}
+		for (_, display, rev) in restores
+		{
+			actions.push(FolderRestoreAction::RestoreFile { path: display, rev: rev });
+		}
+
+		let entries = actions.into_iter()
+			.map(|action|
+			{
+				let status = if dry_run
+				{
+					FolderRestoreStatus::Planned
+				}
+				else
+				{
+					let result = match action
+					{
+						FolderRestoreAction::CreateFolder { ref path } => self.create_folder(CreateFolderArg
+						{
+							path: path.clone(),
+							autorename: false,
+						}).map(|_| ()),
+						FolderRestoreAction::RestoreFile { ref path, ref rev } => self.restore(RestoreArg
+						{
+							path: path.clone(),
+							rev: rev.clone(),
+						}).map(|_| ()),
+					};
+					match result
+					{
+						Err(e) => FolderRestoreStatus::Failed(e),
+						Ok(()) => FolderRestoreStatus::Done,
+					}
+				};
+				FolderRestoreEntry
+				{
+					action: action,
+					status: status,
+				}
+			})
+			.collect();
+		Ok(FolderRestoreReport
+		{
+			path: path.to_owned(),
+			timestamp: timestamp.to_owned(),
+			dry_run: dry_run,
+			entries: entries,
+			skipped: skipped,
+		})
+	}
+
+	/// Restores a file to the revision `nth_previous` steps before its newest revision.
+	/// A `nth_previous` of 0 restores the newest revision, which brings back a deleted file.
+	/// Only the newest 100 revisions can be restored this way.
+	pub fn revert_to(&self, path: &str, nth_previous: usize)
+	-> Result<FileMetadata>
+	{
+		let history = self.history(path, ListRevisionsMode::Path, (nth_previous as u64 + 1).min(100))?;
+		let rev = match history.revisions.get(nth_previous)
+		{
+			None => return Err(DropboxError::MissingRevision),
+			Some(r) => r.rev.clone(),
+		};
+		self.restore(RestoreArg
+		{
+			path: path.to_owned(),
+			rev: rev,
+		})
+	}
+
 	/// Save a specified URL into a file in user's Dropbox.
 	/// If the given path already exists, the file will be renamed to avoid the conflict (e.g. myfile (1).txt).
 	pub fn save_url(&self, arg: SaveUrlArg)
@@ -644,6 +1049,81 @@ impl<'a> DropboxFiles<'a>
 		}
 	}
 
+	/// Add a tag to an item. A tag is a string. The strings are automatically converted to lowercase letters.
+	/// No more than 20 tags can be added to a given item.
+	pub fn tags_add(&self, arg: AddTagArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("files", "tags", "add");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<AddTagError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::AddTagError(r)),
+		}
+	}
+
+	/// Get list of tags assigned to items.
+	pub fn tags_get(&self, arg: GetTagsArg)
+	-> Result<GetTagsResult>
+	{
+		let uri = gen_uri!("files", "tags", "get");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<GetTagsResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<BaseTagError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::BaseTagError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Get the tags of every file and folder of a list_folder or list_folder/continue result page
+	/// with a single tags/get call. Deleted entries are skipped.
+	pub fn tags_get_for_list_folder(&self, result: &ListFolderResult)
+	-> Result<GetTagsResult>
+	{
+		let paths: Vec<String> = result.entries.iter()
+			.filter_map(|entry| match *entry
+			{
+				Metadata::File(ref file) => file.path_lower.clone(),
+				Metadata::Folder(ref folder) => folder.path_lower.clone(),
+				Metadata::Deleted(_) => None,
+			})
+			.collect();
+		if paths.is_empty()
+		{
+			return Ok(GetTagsResult::default());
+		}
+		self.tags_get(GetTagsArg { paths: paths })
+	}
+
+	/// Remove a tag from an item.
+	pub fn tags_remove(&self, arg: RemoveTagArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("files", "tags", "remove");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<RemoveTagError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::RemoveTagError(r)),
+		}
+	}
+
 	/// Create a new file with the contents provided in the request.
 	/// Do not use this to upload a file larger than 150 MB. Instead,
 	/// create an upload session with upload_session/start.
@@ -768,4 +1248,21 @@ impl<'a> DropboxFiles<'a>
 			Ok(r) => Ok(r),
 		}
 	}
+
+	fn get_revision_metadata(&self, rev: &str)
+	-> Result<FileMetadata>
+	{
+		let metadata = self.get_metadata(GetMetadataArg
+		{
+			path: format!("rev:{}", rev),
+			include_media_info: false,
+			include_deleted: false,
+			include_has_explicit_shared_members: false,
+		})?;
+		match metadata
+		{
+			Metadata::File(r) => Ok(r),
+			_ => Err(DropboxError::MissingRevision),
+		}
+	}
 }
@@ -24,7 +24,12 @@
 // extern crates
 #[macro_use] extern crate log;
 #[macro_use] extern crate serde_derive;
+extern crate serde;
 extern crate serde_json;
+extern crate serde_urlencoded;
+extern crate rand;
+extern crate sha2;
+extern crate base64;
 #[macro_use] extern crate hyper;
 extern crate hyper_native_tls;
 #[macro_use] extern crate mime;
@@ -34,82 +39,266 @@ extern crate hyper_native_tls;
 pub mod models;
 pub mod error;
 #[macro_use] mod macros;
+pub mod auth;
+pub mod builder;
+pub mod check;
+pub mod cursor;
+pub mod file_requests;
 pub mod files;
+pub mod oauth2;
+pub mod paper;
+pub mod sharing;
+pub mod secret;
+pub mod store;
 pub mod users;
 // std uses
 use std::fs::File;
+use std::io;
 use std::io::Read;
 use std::io::Write;
 use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 // crate uses
 use hyper::Client;
+use hyper::client::Response;
 use hyper::net::HttpsConnector;
+use hyper::status::StatusCode;
 use hyper_native_tls::NativeTlsClient;
 use hyper::header::*;
 // intern uses
 use ::error::*;
+use ::models::auth::*;
+use ::builder::*;
+use ::models::check::*;
+use ::models::common::*;
+use ::secret::*;
+use ::store::CredentialStore;
+use ::models::error::*;
 // consts or statics
 static BASE_URL: &str = "https://api.dropboxapi.com";
 static UPLOAD_URL: &str = "https://content.dropboxapi.com";
 static API_VERSION: &str = "/2";
 static USER_AGENT: &str = concat!("dropbox-rs (https://github.com/souryo/dropbox-rs, ",
 	env!("CARGO_PKG_VERSION"), ")");
+/// Access tokens which expire within this many seconds are refreshed before the next request.
+const REFRESH_MARGIN: u64 = 300;
 // etc
+type RefreshCallback = Box<dyn Fn(&Credential) + Send + Sync>;
 
 pub struct Dropbox
 {
 	client: hyper::Client,
-	token: String,
+	credential: Mutex<Credential>,
+	on_token_refresh: Option<RefreshCallback>,
+	user_agent: String,
+	retry_policy: RetryPolicy,
+	hosts: ApiHosts,
+	/// The serialized Dropbox-API-Path-Root header.
+	path_root: Option<String>,
 }
 
 impl Dropbox
 {
+	/// Creates a client with the default configuration. Use DropboxBuilder to configure it.
 	pub fn new(token: String)
 	-> Result<Dropbox>
 	{
-		let ssl = NativeTlsClient::new()?;
-		let connector = HttpsConnector::new(ssl);
-		let client = Client::with_connector(connector);
+		DropboxBuilder::new(token).build()
+	}
+
+	/// Creates a client from a credential. If the credential contains a refresh token and the app key,
+	/// the access token is refreshed shortly before it expires and whenever Dropbox reports it as expired.
+	pub fn with_credential(credential: Credential)
+	-> Result<Dropbox>
+	{
+		DropboxBuilder::with_credential(credential).build()
+	}
+
+	/// Creates a client from the token returned at the end of an OAuth2 flow.
+	/// Use OAuth2Flow::credential together with with_credential if the token should be refreshed.
+	pub fn from_token_response(token: TokenResponse)
+	-> Result<Dropbox>
+	{
+		Dropbox::with_credential(Credential
+		{
+			account_id: token.account_id,
+			access_token: token.access_token,
+			expires_at: token.expires_in.map(|e| now() + e),
+			refresh_token: token.refresh_token,
+			..Credential::default()
+		})
+	}
+
+	/// Creates a client from the credential the store holds for the account.
+	/// Whenever the access token is refreshed, the new credential is saved to the store.
+	/// Setting another callback with on_token_refresh replaces the saving.
+	pub fn from_store<S>(store: Arc<S>, account_id: &str)
+	-> Result<Dropbox>
+		where S: CredentialStore + 'static
+	{
+		let credential = match store.load(account_id)?
+		{
+			None => return Err(DropboxError::MissingCredential),
+			Some(r) => r,
+		};
+		let mut dropbox = Dropbox::with_credential(credential)?;
+		let account_id = account_id.to_owned();
+		dropbox.on_token_refresh(move |credential|
+		{
+			if let Err(e) = store.save(&account_id, credential)
+			{
+				error!("failed to save the refreshed credential: {:?}", e);
+			}
+		});
+		Ok(dropbox)
+	}
 
-		Ok(Dropbox
+	/// Sets a callback which is called with the new credential whenever the access token was refreshed,
+	/// e.g. to persist it.
+	pub fn on_token_refresh<F>(&mut self, callback: F)
+		where F: Fn(&Credential) + Send + Sync + 'static
+	{
+		self.on_token_refresh = Some(Box::new(callback));
+	}
+
+	/// Sets or clears the Dropbox-API-Path-Root header of all following requests,
+	/// e.g. to switch into the team space after reading root_info of the current account.
+	pub fn set_path_root(&mut self, path_root: Option<PathRoot>)
+	-> Result<()>
+	{
+		self.path_root = match path_root
 		{
-			client: client,
-			token: token,
+			None => None,
+			Some(ref path_root) => Some(serde_json::to_string(path_root)?),
+		};
+		Ok(())
+	}
+
+	/// Returns a copy of the current credential.
+	pub fn credential(&self)
+	-> Credential
+	{
+		self.lock_credential().clone()
+	}
+
+	/// Refreshes the access token now, regardless of its expiry.
+	pub fn refresh_access_token(&self)
+	-> Result<()>
+	{
+		let mut credential = self.lock_credential();
+		self.refresh(&mut credential)
+	}
+
+	/// Checks the connection to Dropbox with the check/user route.
+	/// A rejected access token is reported in the result instead of as an error.
+	pub fn ping(&self)
+	-> Result<PingResult>
+	{
+		let start = Instant::now();
+		let token_accepted = match check::DropboxCheck::new(self).user(EchoArg { query: "ping".to_owned() })
+		{
+			Err(DropboxError::Auth(_)) => false,
+			Err(e) => return Err(e),
+			Ok(_) => true,
+		};
+		Ok(PingResult
+		{
+			latency: start.elapsed(),
+			token_accepted: token_accepted,
 		})
 	}
 
 	fn send_request(&self, uri: &str, body: &str)
 	-> Result<String>
 	{
-		let header = self.create_headers();
-		debug!("{:?}", &header);
-		let mut resp = if !body.is_empty()
+		let uri = &self.resolve(uri);
+		let mut resp = self.execute(|token|
 		{
-			self.client.post(uri)
-				.headers(header)
-				.body(body)
-				.send()?
-		}
-		else
+			let header = self.create_headers(token);
+			debug!("{}", redact_headers(&header));
+			let request = self.client.post(uri)
+				.headers(header);
+			if !body.is_empty()
+			{
+				request.body(body).send()
+			}
+			else
+			{
+				request.send()
+			}
+		})?;
+		let mut body = String::new();
+		resp.read_to_string(&mut body)?;
+		trace!("{}", redact_body(&body));
+		Ok(body)
+	}
+
+	/// Sends a request authenticated with the app key and secret of the credential instead of the access token.
+	fn send_app_request(&self, uri: &str, body: &str)
+	-> Result<String>
+	{
+		let uri = &self.resolve(uri);
+		let header = self.create_app_headers()?;
+		let mut resp = check_status(self.send_with_retry(&|_: &str|
 		{
 			self.client.post(uri)
-				.headers(header)
-				.send()?
-		};
+				.headers(header.clone())
+				.body(body)
+				.send()
+		}, "")?)?;
 		let mut body = String::new();
 		resp.read_to_string(&mut body)?;
-		trace!("{:?}", &body);
+		trace!("{}", redact_body(&body));
 		Ok(body)
 	}
 
 	fn download(&self, uri: &str, arg: &str, file_path: &Path)
 	-> Result<String>
 	{
-		let header = self.create_content_headers(&arg);
-		debug!("{:?}", &header);
-		let mut resp = self.client.post(uri)
-			.headers(header)
-			.send()?;
+		self.download_with(uri, arg, |resp|
+		{
+			let mut file = File::create(file_path)?;
+			io::copy(resp, &mut file)?;
+			Ok(())
+		})
+	}
+
+	/// Streams the downloaded content into the writer instead of a file.
+	fn download_to<W: Write>(&self, uri: &str, arg: &str, writer: &mut W)
+	-> Result<String>
+	{
+		self.download_with(uri, arg, |resp|
+		{
+			io::copy(resp, writer)?;
+			Ok(())
+		})
+	}
+
+	/// Sends a download request and returns the Dropbox-API-Result header.
+	/// The content is only passed to write if the request succeeded,
+	/// otherwise the error body is returned in place of the header.
+	fn download_with<F>(&self, uri: &str, arg: &str, write: F)
+	-> Result<String>
+		where F: FnOnce(&mut Response) -> Result<()>
+	{
+		let uri = &self.resolve(uri);
+		let mut resp = self.execute(|token|
+		{
+			let header = self.create_content_headers(token, arg);
+			debug!("{}", redact_headers(&header));
+			self.client.post(uri)
+				.headers(header)
+				.send()
+		})?;
+		if !resp.status.is_success()
+		{
+			let mut body = String::new();
+			resp.read_to_string(&mut body)?;
+			trace!("{}", redact_body(&body));
+			return Ok(body);
+		}
 		let api_resp = match resp.headers.iter()
 			.find(|i| i.name() == "dropbox-api-result")
 			.map(|i| i.value_string())
@@ -117,51 +306,288 @@ impl Dropbox
 			None => return Err(DropboxError::MissingDropboxApiResult),
 			Some(r) => r,
 		};
-		trace!("{:?}", &api_resp);
-		let mut buffer = vec![];
-		resp.read_to_end(&mut buffer)?;
-		let mut file = File::create(file_path)?;
-		file.write_all(&buffer)?;
+		trace!("{}", redact_body(&api_resp));
+		write(&mut resp)?;
 		Ok(api_resp)
 	}
 
 	fn upload(&self, uri: &str, arg: &str, file_path: &Path)
 	-> Result<String>
 	{
+		let uri = &self.resolve(uri);
 		let mut file = File::open(file_path)?;
 		let mut contents = String::new();
 		file.read_to_string(&mut contents)?;
-		let mut header = self.create_content_headers(&arg);
-		header.set(ContentType(mime!(Application/OctetStream)));
-		debug!("{:?}", &header);
-		let mut resp = self.client.post(uri)
-			.headers(header)
-			.body(&contents)
-			.send()?;
+		let mut resp = self.execute(|token|
+		{
+			let mut header = self.create_content_headers(token, arg);
+			header.set(ContentType(mime!(Application/OctetStream)));
+			debug!("{}", redact_headers(&header));
+			self.client.post(uri)
+				.headers(header)
+				.body(&contents)
+				.send()
+		})?;
 		let mut body = String::new();
 		resp.read_to_string(&mut body)?;
-		trace!("{:?}", &body);
+		trace!("{}", redact_body(&body));
 		Ok(body)
 	}
 
-	fn create_headers(&self)
+	/// Sends a request with the current access token.
+	/// If Dropbox reports the access token as expired, it is refreshed and the request is sent once more.
+	fn execute<F>(&self, send: F)
+	-> Result<Response>
+		where F: Fn(&str) -> hyper::Result<Response>
+	{
+		let token = self.access_token()?;
+		let err = match check_status(self.send_with_retry(&send, token.expose())?)
+		{
+			Err(DropboxError::Auth(err)) => err,
+			r => return r,
+		};
+		let token =
+		{
+			let mut credential = self.lock_credential();
+			if err.error != AuthError::ExpiredAccessToken
+				|| credential.refresh_token.is_none() || credential.app_key.is_none()
+			{
+				return Err(DropboxError::Auth(err));
+			}
+			// another request may have refreshed the token in the meantime
+			if credential.access_token == token
+			{
+				self.refresh(&mut credential)?;
+			}
+			credential.access_token.clone()
+		};
+		check_status(self.send_with_retry(&send, token.expose())?)
+	}
+
+	/// Sends a request and retries it according to the retry policy
+	/// while Dropbox answers with 429 Too Many Requests or refuses the connection.
+	/// A 5xx status, a timeout or a reset isn't retried, as the request may already have been carried out.
+	fn send_with_retry<F>(&self, send: &F, token: &str)
+	-> Result<Response>
+		where F: Fn(&str) -> hyper::Result<Response>
+	{
+		let mut attempt = 0;
+		loop
+		{
+			let result = send(token);
+			if attempt >= self.retry_policy.max_retries
+			{
+				return Ok(result?);
+			}
+			let delay = match result
+			{
+				Ok(ref resp) if resp.status == StatusCode::TooManyRequests => retry_after(resp)
+					.unwrap_or_else(|| self.retry_policy.delay(attempt)),
+				Err(hyper::Error::Io(ref err)) if err.kind() == io::ErrorKind::ConnectionRefused =>
+				{
+					debug!("connection failed: {}", err);
+					self.retry_policy.delay(attempt)
+				},
+				r => return Ok(r?),
+			};
+			debug!("retrying after {:?}", delay);
+			attempt += 1;
+			thread::sleep(delay);
+		}
+	}
+
+	/// Replaces the default api and content hosts of the uri with the configured ones.
+	fn resolve(&self, uri: &str)
+	-> String
+	{
+		if let Some(route) = uri.strip_prefix(BASE_URL)
+		{
+			format!("{}{}", self.hosts.api, route)
+		}
+		else if let Some(route) = uri.strip_prefix(UPLOAD_URL)
+		{
+			format!("{}{}", self.hosts.content, route)
+		}
+		else
+		{
+			uri.to_owned()
+		}
+	}
+
+	/// Returns the access token, refreshing it first if it is about to expire.
+	fn access_token(&self)
+	-> Result<Secret>
+	{
+		let mut credential = self.lock_credential();
+		let expires_soon = credential.expires_at
+			.is_some_and(|expires_at| expires_at <= now() + REFRESH_MARGIN);
+		if expires_soon && credential.refresh_token.is_some() && credential.app_key.is_some()
+		{
+			self.refresh(&mut credential)?;
+		}
+		Ok(credential.access_token.clone())
+	}
+
+	fn refresh(&self, credential: &mut Credential)
+	-> Result<()>
+	{
+		let refresh_token = match credential.refresh_token
+		{
+			None => return Err(DropboxError::MissingRefreshToken),
+			Some(ref r) => r.clone(),
+		};
+		let app_key = match credential.app_key
+		{
+			None => return Err(DropboxError::MissingAppKey),
+			Some(ref r) => r.clone(),
+		};
+		let body = serde_urlencoded::to_string(&RefreshToken
+		{
+			grant_type: "refresh_token".to_owned(),
+			refresh_token: refresh_token,
+			client_id: app_key,
+			client_secret: credential.app_secret.clone(),
+		})?;
+		let token = oauth2::request_token(&self.client, &self.hosts.api, &self.user_agent, &body)?;
+		credential.access_token = token.access_token;
+		credential.expires_at = token.expires_in.map(|e| now() + e);
+		if let Some(ref callback) = self.on_token_refresh
+		{
+			callback(credential);
+		}
+		Ok(())
+	}
+
+	fn lock_credential<'a>(&'a self)
+	-> MutexGuard<'a, Credential>
+	{
+		match self.credential.lock()
+		{
+			Err(e) => e.into_inner(),
+			Ok(r) => r,
+		}
+	}
+
+	fn create_headers(&self, token: &str)
 	-> Headers
 	{
 		let mut header = Headers::new();
-		header.set(Authorization(Bearer { token: self.token.clone() }));
-		header.set(UserAgent(USER_AGENT.to_owned()));
+		header.set(Authorization(Bearer { token: token.to_owned() }));
+		header.set(UserAgent(self.user_agent.clone()));
 		header.set(ContentType::json());
+		self.add_path_root_header(&mut header);
 		header
 	}
 
-	fn create_content_headers(&self, arg: &str)
+	fn create_app_headers(&self)
+	-> Result<Headers>
+	{
+		let credential = self.lock_credential();
+		let app_key = match credential.app_key
+		{
+			None => return Err(DropboxError::MissingAppKey),
+			Some(ref r) => r.clone(),
+		};
+		let app_secret = match credential.app_secret
+		{
+			None => return Err(DropboxError::MissingAppSecret),
+			Some(ref r) => r.clone(),
+		};
+		let mut header = Headers::new();
+		header.set(Authorization(Basic { username: app_key, password: Some(app_secret.expose().to_owned()) }));
+		header.set(UserAgent(self.user_agent.clone()));
+		header.set(ContentType::json());
+		Ok(header)
+	}
+
+	fn create_content_headers(&self, token: &str, arg: &str)
 	-> Headers
 	{
 		header!{ (DropboxApiArg, "Dropbox-API-Arg") => [String] };
 		let mut header = Headers::new();
-		header.set(Authorization(Bearer { token: self.token.clone() }));
-		header.set(UserAgent(USER_AGENT.to_owned()));
+		header.set(Authorization(Bearer { token: token.to_owned() }));
+		header.set(UserAgent(self.user_agent.clone()));
 		header.set(DropboxApiArg(arg.to_owned()));
+		self.add_path_root_header(&mut header);
 		header
 	}
+
+	fn add_path_root_header(&self, header: &mut Headers)
+	{
+		if let Some(ref path_root) = self.path_root
+		{
+			header.set_raw("Dropbox-API-Path-Root", vec![path_root.clone().into_bytes()]);
+		}
+	}
+}
+
+/// Turns a 401 response into DropboxError::Auth and a 422 response into DropboxError::PathRootError.
+fn check_status(mut resp: Response)
+-> Result<Response>
+{
+	if resp.status != StatusCode::Unauthorized && resp.status != StatusCode::UnprocessableEntity
+	{
+		return Ok(resp);
+	}
+	let mut body = String::new();
+	resp.read_to_string(&mut body)?;
+	trace!("{}", redact_body(&body));
+	if resp.status == StatusCode::UnprocessableEntity
+	{
+		return match serde_json::from_str::<Error<PathRootError>>(&body)
+		{
+			Err(_) => Err(DropboxError::Other),
+			Ok(r) => Err(DropboxError::PathRootError(r)),
+		};
+	}
+	match serde_json::from_str::<Error<AuthError>>(&body)
+	{
+		Err(_) => Err(DropboxError::Other),
+		Ok(r) => Err(DropboxError::Auth(r)),
+	}
+}
+
+/// Reads the seconds of the Retry-After header.
+fn retry_after(resp: &Response)
+-> Option<Duration>
+{
+	resp.headers.get_raw("Retry-After")
+		.and_then(|v| v.first())
+		.and_then(|v| String::from_utf8_lossy(v).trim().parse().ok())
+		.map(Duration::from_secs)
+}
+
+fn create_client()
+-> Result<Client>
+{
+	let ssl = NativeTlsClient::new()?;
+	let connector = HttpsConnector::new(ssl);
+	Ok(Client::with_connector(connector))
+}
+
+/// Seconds since the unix epoch.
+fn now()
+-> u64
+{
+	SystemTime::now().duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0)
+}
+
+/// Formats seconds since the unix epoch the way Dropbox formats timestamps, e.g. "2015-05-12T15:50:38Z".
+fn format_timestamp(secs: u64)
+-> String
+{
+	// civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+	let z = secs / 86400 + 719468;
+	let era = z / 146097;
+	let doe = z - era * 146097;
+	let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+	let time = secs % 86400;
+	format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3600, time % 3600 / 60, time % 60)
 }
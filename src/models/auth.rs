@@ -1,17 +1,111 @@
+use ::secret::Secret;
+
+/// Request body of the /oauth2/token route.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Token
 {
-	code: String,
-	grant_type: String,
-	client_id: String,
-	redirect_uri: String,
+	/// The code acquired by directing users to /oauth2/authorize?response_type=code.
+	pub code: Secret,
+	/// The grant type, which must be authorization_code.
+	pub grant_type: String,
+	/// The app's key, found in the App Console.
+	pub client_id: String,
+	/// The app's secret, found in the App Console.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub client_secret: Option<Secret>,
+	/// Only used to validate that it matches the original /oauth2/authorize, not used to redirect again.
+	pub redirect_uri: String,
+	/// The client generated string used to derive the code_challenge of the PKCE flow.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub code_verifier: Option<Secret>,
+}
+
+/// Request body of the /oauth2/token route to refresh a short-lived access token.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RefreshToken
+{
+	/// The grant type, which must be refresh_token.
+	pub grant_type: String,
+	/// The refresh token returned together with the first access token.
+	pub refresh_token: Secret,
+	/// The app's key, found in the App Console.
+	pub client_id: String,
+	/// The app's secret, found in the App Console. Not needed for tokens acquired with PKCE.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub client_secret: Option<Secret>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct TokenResponse
 {
-	access_token: String,
-	token_type: String,
-	account_id: String,
-	uid: Option<String>,
+	/// The access token to be used to call the Dropbox API.
+	pub access_token: Secret,
+	/// Will always be bearer.
+	pub token_type: String,
+	/// The Dropbox account ID of the user who authorized the app.
+	/// This field is empty when refreshing an access token.
+	#[serde(default)]
+	pub account_id: String,
+	/// The deprecated user ID of the user. This field is optional.
+	pub uid: Option<String>,
+	/// The length of time in seconds that the access token will be valid for.
+	/// This field is only present for short-lived access tokens.
+	pub expires_in: Option<u64>,
+	/// A refresh token which can be used to acquire a new access token.
+	/// This field is only present if token_access_type was offline.
+	pub refresh_token: Option<Secret>,
+	/// The permission set applied to the token. This field is optional.
+	pub scope: Option<String>,
+}
+
+/// The type of token the /oauth2/authorize route should issue.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TokenAccessType
+{
+	/// Only a short-lived access token is returned.
+	#[serde(rename="online")]
+	Online,
+	/// A short-lived access token and a long-lived refresh token are returned.
+	#[serde(rename="offline")]
+	Offline,
+	/// A long-lived access token is returned. This is deprecated by Dropbox.
+	#[serde(rename="legacy")]
+	Legacy,
+}
+
+/// The credential Dropbox authorizes its requests with.
+/// Short-lived access tokens are refreshed automatically if refresh_token and app_key are set.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Credential
+{
+	/// The Dropbox account ID of the user who authorized the app. Credential stores are keyed by it.
+	#[serde(default)]
+	pub account_id: String,
+	/// The access token to be used to call the Dropbox API.
+	pub access_token: Secret,
+	/// The time the access token expires at, in seconds since the unix epoch.
+	/// None if the access token is long-lived.
+	pub expires_at: Option<u64>,
+	/// A refresh token which can be used to acquire a new access token.
+	pub refresh_token: Option<Secret>,
+	/// The app's key. Required to refresh the access token.
+	pub app_key: Option<String>,
+	/// The app's secret. Not needed for tokens acquired with PKCE.
+	pub app_secret: Option<Secret>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct TokenFromOAuth1Arg
+{
+	/// The supplied OAuth 1.0 access token.
+	pub oauth1_token: Secret,
+	/// The token secret associated with the supplied access token.
+	pub oauth1_token_secret: Secret,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TokenFromOAuth1Result
+{
+	/// The OAuth 2.0 token generated from the supplied OAuth 1.0 token.
+	pub oauth2_token: Secret,
 }
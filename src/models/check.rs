@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+/// Contains the arguments to be sent to the Dropbox servers.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct EchoArg
+{
+	/// The string that you'd like to be echoed back to you.
+	pub query: String,
+}
+
+/// Echo result contains the result of the echo.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct EchoResult
+{
+	/// If everything worked correctly, this would be the same as query.
+	pub result: String,
+}
+
+/// The outcome of Dropbox::ping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PingResult
+{
+	/// The time the check/user round trip took.
+	pub latency: Duration,
+	/// Whether Dropbox accepted the access token.
+	pub token_accepted: bool,
+}
@@ -0,0 +1,81 @@
+/// The namespace relative to which paths are resolved, sent in the Dropbox-API-Path-Root header.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PathRoot
+{
+	/// Paths are relative to the authenticating user's home namespace, whether or not that user belongs to a team.
+	#[serde(rename="home")]
+	Home,
+	/// Paths are relative to the authenticating user's root namespace.
+	/// This results in PathRootError.invalid_root if the user's root namespace has changed.
+	#[serde(rename="root")]
+	Root
+	{
+		root: String,
+	},
+	/// Paths are relative to given namespace id.
+	/// This results in PathRootError.no_permission if you don't have access to this namespace.
+	#[serde(rename="namespace_id")]
+	NamespaceId
+	{
+		namespace_id: String,
+	},
+}
+
+/// Information about the user's root namespace and home namespace.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RootInfo
+{
+	/// Root info when user is member of a team with a separate root namespace and home namespace.
+	#[serde(rename="team")]
+	Team(TeamRootInfo),
+	/// Root info when user is not member of a team or the user is a member of a team
+	/// and the team does not have a separate root namespace.
+	#[serde(rename="user")]
+	User(UserRootInfo),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TeamRootInfo
+{
+	/// The namespace ID for user's root namespace. It will be the namespace ID of the shared team root
+	/// if the user is member of a team with a separate team root. Otherwise it will be same as home_namespace_id.
+	pub root_namespace_id: String,
+	/// The namespace ID for user's home namespace.
+	pub home_namespace_id: String,
+	/// The path for user's home directory under the shared team root.
+	pub home_path: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct UserRootInfo
+{
+	/// The namespace ID for user's root namespace.
+	pub root_namespace_id: String,
+	/// The namespace ID for user's home namespace.
+	pub home_namespace_id: String,
+}
+
+impl RootInfo
+{
+	pub fn root_namespace_id(&self)
+	-> &str
+	{
+		match *self
+		{
+			RootInfo::Team(ref r) => &r.root_namespace_id,
+			RootInfo::User(ref r) => &r.root_namespace_id,
+		}
+	}
+
+	pub fn home_namespace_id(&self)
+	-> &str
+	{
+		match *self
+		{
+			RootInfo::Team(ref r) => &r.home_namespace_id,
+			RootInfo::User(ref r) => &r.home_namespace_id,
+		}
+	}
+}
@@ -1,8 +1,68 @@
+use ::models::common::RootInfo;
+use ::models::sharing::*;
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Error<T>
 {
-	error_summary: String,
-	error: T,
+	pub error_summary: String,
+	pub error: T,
+}
+
+/// Error returned by the /oauth2/token route.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct OAuth2Error
+{
+	/// The OAuth2 error code, e.g. invalid_grant.
+	pub error: String,
+	/// A description of the error. This field is optional.
+	pub error_description: Option<String>,
+}
+
+/// Error returned with a 401 status by every route if the request couldn't be authenticated.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AuthError
+{
+	/// The access token is invalid.
+	#[serde(rename="invalid_access_token")]
+	InvalidAccessToken,
+	/// The user specified in 'Dropbox-API-Select-User' is no longer on the team.
+	#[serde(rename="invalid_select_user")]
+	InvalidSelectUser,
+	/// The user specified in 'Dropbox-API-Select-Admin' is not a Dropbox Business team admin.
+	#[serde(rename="invalid_select_admin")]
+	InvalidSelectAdmin,
+	/// The user has been suspended.
+	#[serde(rename="user_suspended")]
+	UserSuspended,
+	/// The access token has expired.
+	#[serde(rename="expired_access_token")]
+	ExpiredAccessToken,
+	/// The access token does not have the required scope to access the route.
+	#[serde(rename="missing_scope")]
+	MissingScope(TokenScopeError),
+	/// The route is not available to public.
+	#[serde(rename="route_access_denied")]
+	RouteAccessDenied,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TokenScopeError
+{
+	/// The required scope to access the route.
+	pub required_scope: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum TokenFromOAuth1Error
+{
+	/// Part or all of the OAuth 1.0 access token info is invalid.
+	#[serde(rename="invalid_oauth1_token_info")]
+	InvalidOAuth1TokenInfo,
+	/// The authorized app does not match the app associated with the supplied access token.
+	#[serde(rename="app_id_mismatch")]
+	AppIdMismatch,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -168,7 +228,12 @@ pub enum DownloadError
 pub enum GetMetadataError
 {
 	#[serde(rename="path")]
-	Path(LookupError),
+	Path
+	{
+		path: LookupError,
+	},
+	#[serde(other)]
+	Other,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -239,7 +304,12 @@ pub enum ListFolderLongpollError
 pub enum ListRevisionsError
 {
 	#[serde(rename="path")]
-	Path(LookupError),
+	Path
+	{
+		path: LookupError,
+	},
+	#[serde(other)]
+	Other,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -261,11 +331,36 @@ pub enum DeleteError
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
 pub enum LookUpPropertiesError
 {
 	/// his property group does not exist for this file.
 	#[serde(rename="property_group_not_found")]
 	PropertyGroupNotFound,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PropertiesSearchError
+{
+	#[serde(rename="property_group_lookup")]
+	PropertyGroupLookup
+	{
+		property_group_lookup: LookUpPropertiesError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PropertiesSearchContinueError
+{
+	/// Indicates that the cursor has been invalidated. Call properties/search to obtain a new cursor.
+	#[serde(rename="reset")]
+	Reset,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -282,7 +377,10 @@ pub enum LookupError
 {
 	/// This field is optional.
 	#[serde(rename="malformed_path")]
-	MalformedPath(Option<String>),
+	MalformedPath
+	{
+		malformed_path: Option<String>,
+	},
 	/// There is nothing at the given path.
 	#[serde(rename="not_found")]
 	NotFound,
@@ -296,17 +394,26 @@ pub enum LookupError
 	/// For example, sometimes there are legal restrictions due to copyright claims.
 	#[serde(rename="restricted_content")]
 	RestrictedContent,
-	// I have no idea where the heck I found this, but it seems it dosn't belong anywhere
-	// so I commented it out.
-	// #[serde(rename="invalid_path_root")]
-	// InvalidPathRoot(PathRootError)
+	#[serde(other)]
+	Other,
 }
 
-// #[derive(Deserialize, Debug, Clone, Default, PartialEq)]
-// pub struct PathRootError
-// {
-// 	pub path_root: Option<String>,
-// }
+/// Error returned with a 422 status by every route if the Dropbox-API-Path-Root header can't be applied.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PathRootError
+{
+	/// The root namespace id in Dropbox-API-Path-Root header is not valid.
+	/// The value of this error is the user's latest root info.
+	#[serde(rename="invalid_root")]
+	InvalidRoot
+	{
+		invalid_root: RootInfo,
+	},
+	/// You don't have permission to access the namespace id in Dropbox-API-Path-Root header.
+	#[serde(rename="no_permission")]
+	NoPermission,
+}
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag=".tag")]
@@ -348,6 +455,51 @@ pub enum SearchError
 	Path(LookupError),
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AddTagError
+{
+	#[serde(rename="path")]
+	Path
+	{
+		path: LookupError,
+	},
+	/// The item already has the maximum supported number of tags.
+	#[serde(rename="too_many_tags")]
+	TooManyTags,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum BaseTagError
+{
+	#[serde(rename="path")]
+	Path
+	{
+		path: LookupError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RemoveTagError
+{
+	#[serde(rename="path")]
+	Path
+	{
+		path: LookupError,
+	},
+	/// That tag doesn't exist at this path.
+	#[serde(rename="tag_not_present")]
+	TagNotPresent,
+	#[serde(other)]
+	Other,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag=".tag")]
 pub enum UploadError
@@ -423,6 +575,1050 @@ pub enum GetAccountError
 #[serde(tag=".tag")]
 pub enum GetAccountBatchError
 {
+	/// The value is an account ID specified in GetAccountBatchArg.account_ids that does not exist.
 	#[serde(rename="no_account")]
-	NoAccount(String),
+	NoAccount
+	{
+		no_account: String,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum CreateSharedLinkWithSettingsError
+{
+	#[serde(rename="path")]
+	Path
+	{
+		path: LookupError,
+	},
+	/// This user's email address is not verified. This functionality is only available on accounts with a verified email address.
+	#[serde(rename="email_not_verified")]
+	EmailNotVerified,
+	/// The shared link already exists. You can call list_shared_links to get the existing link,
+	/// or use the provided metadata if it is returned.
+	#[serde(rename="shared_link_already_exists")]
+	SharedLinkAlreadyExists
+	{
+		shared_link_already_exists: Option<SharedLinkAlreadyExistsMetadata>,
+	},
+	/// There is an error with the given settings.
+	#[serde(rename="settings_error")]
+	SettingsError
+	{
+		settings_error: SharedLinkSettingsError,
+	},
+	/// The user is not allowed to create a shared link to the specified file.
+	/// For example, this can occur if the file is restricted or if the user's links are banned.
+	#[serde(rename="access_denied")]
+	AccessDenied,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedLinkAlreadyExistsMetadata
+{
+	/// Metadata of the shared link that already exists.
+	#[serde(rename="metadata")]
+	Metadata
+	{
+		metadata: SharedLinkMetadata,
+	},
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedLinkSettingsError
+{
+	/// The given settings are invalid (for example, all attributes of the SharedLinkSettings are empty,
+	/// the requested visibility is RequestedVisibility.password but the SharedLinkSettings.link_password
+	/// is missing, SharedLinkSettings.expires is set to the past, etc.).
+	#[serde(rename="invalid_settings")]
+	InvalidSettings,
+	/// User is not allowed to modify the settings of this link.
+	#[serde(rename="not_authorized")]
+	NotAuthorized,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListSharedLinksError
+{
+	#[serde(rename="path")]
+	Path
+	{
+		path: LookupError,
+	},
+	/// Indicates that the cursor has been invalidated. Call list_shared_links to obtain a new cursor.
+	#[serde(rename="reset")]
+	Reset,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ModifySharedLinkSettingsError
+{
+	/// The shared link wasn't found.
+	#[serde(rename="shared_link_not_found")]
+	SharedLinkNotFound,
+	/// The caller is not allowed to access this shared link.
+	#[serde(rename="shared_link_access_denied")]
+	SharedLinkAccessDenied,
+	/// This type of link is not supported; use files.export instead.
+	#[serde(rename="unsupported_link_type")]
+	UnsupportedLinkType,
+	/// There is an error with the given settings.
+	#[serde(rename="settings_error")]
+	SettingsError
+	{
+		settings_error: SharedLinkSettingsError,
+	},
+	/// This user's email address is not verified. This functionality is only available on accounts with a verified email address.
+	#[serde(rename="email_not_verified")]
+	EmailNotVerified,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RevokeSharedLinkError
+{
+	/// The shared link wasn't found.
+	#[serde(rename="shared_link_not_found")]
+	SharedLinkNotFound,
+	/// The caller is not allowed to access this shared link.
+	#[serde(rename="shared_link_access_denied")]
+	SharedLinkAccessDenied,
+	/// This type of link is not supported; use files.export instead.
+	#[serde(rename="unsupported_link_type")]
+	UnsupportedLinkType,
+	/// Shared link is malformed.
+	#[serde(rename="shared_link_malformed")]
+	SharedLinkMalformed,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedLinkError
+{
+	/// The shared link wasn't found.
+	#[serde(rename="shared_link_not_found")]
+	SharedLinkNotFound,
+	/// The caller is not allowed to access this shared link.
+	#[serde(rename="shared_link_access_denied")]
+	SharedLinkAccessDenied,
+	/// This type of link is not supported; use files.export instead.
+	#[serde(rename="unsupported_link_type")]
+	UnsupportedLinkType,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum GetSharedLinkFileError
+{
+	/// The shared link wasn't found.
+	#[serde(rename="shared_link_not_found")]
+	SharedLinkNotFound,
+	/// The caller is not allowed to access this shared link.
+	#[serde(rename="shared_link_access_denied")]
+	SharedLinkAccessDenied,
+	/// This type of link is not supported; use files.export instead.
+	#[serde(rename="unsupported_link_type")]
+	UnsupportedLinkType,
+	/// Directories cannot be retrieved by this endpoint.
+	#[serde(rename="shared_link_is_directory")]
+	SharedLinkIsDirectory,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ShareFolderError
+{
+	/// This user's email address is not verified. This functionality is only available on accounts with a verified email address.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// ShareFolderArg.path is invalid.
+	#[serde(rename="bad_path")]
+	BadPath
+	{
+		bad_path: SharePathError,
+	},
+	/// Team policy is more restrictive than ShareFolderArg.member_policy.
+	#[serde(rename="team_policy_disallows_member_policy")]
+	TeamPolicyDisallowsMemberPolicy,
+	/// The current user's account is not allowed to select the specified ShareFolderArg.shared_link_policy.
+	#[serde(rename="disallowed_shared_link_policy")]
+	DisallowedSharedLinkPolicy,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharePathError
+{
+	/// A file is at the specified path.
+	#[serde(rename="is_file")]
+	IsFile,
+	/// We do not support sharing a folder inside a shared folder.
+	#[serde(rename="inside_shared_folder")]
+	InsideSharedFolder,
+	/// We do not support shared folders that contain shared folders.
+	#[serde(rename="contains_shared_folder")]
+	ContainsSharedFolder,
+	/// We do not support shared folders that contain app folders.
+	#[serde(rename="contains_app_folder")]
+	ContainsAppFolder,
+	/// We do not support shared folders that contain team folders.
+	#[serde(rename="contains_team_folder")]
+	ContainsTeamFolder,
+	/// We do not support sharing an app folder.
+	#[serde(rename="is_app_folder")]
+	IsAppFolder,
+	/// We do not support sharing a folder inside an app folder.
+	#[serde(rename="inside_app_folder")]
+	InsideAppFolder,
+	/// A public folder can't be shared this way. Use a public link instead.
+	#[serde(rename="is_public_folder")]
+	IsPublicFolder,
+	/// A folder inside a public folder can't be shared this way. Use a public link instead.
+	#[serde(rename="inside_public_folder")]
+	InsidePublicFolder,
+	/// Folder is already shared. Contains metadata about the existing shared folder.
+	#[serde(rename="already_shared")]
+	AlreadyShared(SharedFolderMetadata),
+	/// Path is not valid.
+	#[serde(rename="invalid_path")]
+	InvalidPath,
+	/// We do not support sharing a Mac OS X package.
+	#[serde(rename="is_osx_package")]
+	IsOsxPackage,
+	/// We do not support sharing a folder inside a Mac OS X package.
+	#[serde(rename="inside_osx_package")]
+	InsideOsxPackage,
+	#[serde(other)]
+	Other,
+}
+
+/// Error occurred while performing an asynchronous job from unshare_folder, remove_folder_member
+/// or relinquish_folder_membership.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum JobError
+{
+	/// Error occurred while performing unshare_folder action.
+	#[serde(rename="unshare_folder_error")]
+	UnshareFolderError
+	{
+		unshare_folder_error: UnshareFolderError,
+	},
+	/// Error occurred while performing remove_folder_member action.
+	#[serde(rename="remove_folder_member_error")]
+	RemoveFolderMemberError
+	{
+		remove_folder_member_error: RemoveFolderMemberError,
+	},
+	/// Error occurred while performing relinquish_folder_membership action.
+	#[serde(rename="relinquish_folder_membership_error")]
+	RelinquishFolderMembershipError
+	{
+		relinquish_folder_membership_error: RelinquishFolderMembershipError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+/// There is an error accessing the shared folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedFolderAccessError
+{
+	/// This shared folder ID is invalid.
+	#[serde(rename="invalid_id")]
+	InvalidId,
+	/// The user is not a member of the shared folder thus cannot access it.
+	#[serde(rename="not_a_member")]
+	NotAMember,
+	/// Never set.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// The shared folder is unmounted.
+	#[serde(rename="unmounted")]
+	Unmounted,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum UnshareFolderError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// This action cannot be performed on a team shared folder.
+	#[serde(rename="team_folder")]
+	TeamFolder,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// This shared folder has too many files to be unshared.
+	#[serde(rename="too_many_files")]
+	TooManyFiles,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AddFolderMemberError
+{
+	/// Unable to access shared folder.
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// This user's email address is not verified. This functionality is only available on accounts with a verified email address.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// The current user has been banned.
+	#[serde(rename="banned_member")]
+	BannedMember,
+	/// AddFolderMemberArg.members contains a bad invitation recipient.
+	#[serde(rename="bad_member")]
+	BadMember
+	{
+		bad_member: AddMemberSelectorError,
+	},
+	/// Your team policy does not allow sharing outside of the team.
+	#[serde(rename="cant_share_outside_team")]
+	CantShareOutsideTeam,
+	/// The value is the member limit that was reached.
+	#[serde(rename="too_many_members")]
+	TooManyMembers
+	{
+		too_many_members: u64,
+	},
+	/// The value is the pending invite limit that was reached.
+	#[serde(rename="too_many_pending_invites")]
+	TooManyPendingInvites
+	{
+		too_many_pending_invites: u64,
+	},
+	/// The current user has hit the limit of invites they can send per day. Try again in 24 hours.
+	#[serde(rename="rate_limit")]
+	RateLimit,
+	/// The current user is trying to share with too many people at once.
+	#[serde(rename="too_many_invitees")]
+	TooManyInvitees,
+	/// The current user's account doesn't support this action. An example of this is when adding a read-only member.
+	/// This action can only be performed by users that have upgraded to a Pro or Business plan.
+	#[serde(rename="insufficient_plan")]
+	InsufficientPlan,
+	/// This action cannot be performed on a team shared folder.
+	#[serde(rename="team_folder")]
+	TeamFolder,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// Invalid shared folder error will be returned as an access_error.
+	#[serde(rename="invalid_shared_folder")]
+	InvalidSharedFolder,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AddMemberSelectorError
+{
+	/// Automatically created groups can only be added to team folders.
+	#[serde(rename="automatic_group")]
+	AutomaticGroup,
+	/// The value is the ID that could not be identified.
+	#[serde(rename="invalid_dropbox_id")]
+	InvalidDropboxId
+	{
+		invalid_dropbox_id: String,
+	},
+	/// The value is the e-email address that is malformed.
+	#[serde(rename="invalid_email")]
+	InvalidEmail
+	{
+		invalid_email: String,
+	},
+	/// The value is the ID of the Dropbox user with an unverified email address.
+	/// Invite unverified users by email address instead of by their Dropbox ID.
+	#[serde(rename="unverified_dropbox_id")]
+	UnverifiedDropboxId
+	{
+		unverified_dropbox_id: String,
+	},
+	/// At least one of the specified groups in AddFolderMemberArg.members is deleted.
+	#[serde(rename="group_deleted")]
+	GroupDeleted,
+	/// Sharing to a group that is not on the current user's team.
+	#[serde(rename="group_not_on_team")]
+	GroupNotOnTeam,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RemoveFolderMemberError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	#[serde(rename="member_error")]
+	MemberError
+	{
+		member_error: SharedFolderMemberError,
+	},
+	/// The target user is the owner of the shared folder. You can't remove this user until ownership
+	/// has been transferred to another member.
+	#[serde(rename="folder_owner")]
+	FolderOwner,
+	/// The target user has access to the shared folder via a group.
+	#[serde(rename="group_access")]
+	GroupAccess,
+	/// This action cannot be performed on a team shared folder.
+	#[serde(rename="team_folder")]
+	TeamFolder,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// This shared folder has too many files for leaving a copy.
+	/// You can still remove this user without leaving a copy.
+	#[serde(rename="too_many_files")]
+	TooManyFiles,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedFolderMemberError
+{
+	/// The target dropbox_id is invalid.
+	#[serde(rename="invalid_dropbox_id")]
+	InvalidDropboxId,
+	/// The target dropbox_id is not a member of the shared folder.
+	#[serde(rename="not_a_member")]
+	NotAMember,
+	/// The target member only has inherited access to the shared folder.
+	#[serde(rename="no_explicit_access")]
+	NoExplicitAccess(MemberAccessLevelResult),
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum UpdateFolderMemberError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	#[serde(rename="member_error")]
+	MemberError
+	{
+		member_error: SharedFolderMemberError,
+	},
+	/// If updating the access type required the member to be added to the shared folder and there was an error when adding the member.
+	#[serde(rename="no_explicit_access")]
+	NoExplicitAccess
+	{
+		no_explicit_access: AddFolderMemberError,
+	},
+	/// The current user's account doesn't support this action. An example of this is when downgrading a member from editor to viewer.
+	/// This action can only be performed by users that have upgraded to a Pro or Business plan.
+	#[serde(rename="insufficient_plan")]
+	InsufficientPlan,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFolderMembersContinueError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// ListFolderMembersContinueArg.cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	#[serde(other)]
+	Other,
+}
+
+/// User account had a problem preventing this action.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharingUserError
+{
+	/// This user's email address is not verified. This functionality is only available on accounts with a verified email address.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	#[serde(other)]
+	Other,
+}
+
+/// User could not access this file.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharingFileAccessError
+{
+	/// Current user does not have sufficient privileges to perform the desired action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// File specified was not found.
+	#[serde(rename="invalid_file")]
+	InvalidFile,
+	/// A folder can't be shared this way. Use folder sharing or a shared link instead.
+	#[serde(rename="is_folder")]
+	IsFolder,
+	/// A file inside a public folder can't be shared this way. Use a public link instead.
+	#[serde(rename="inside_public_folder")]
+	InsidePublicFolder,
+	/// A Mac OS X package can't be shared this way. Use a shared link instead.
+	#[serde(rename="inside_osx_package")]
+	InsideOsxPackage,
+	#[serde(other)]
+	Other,
+}
+
+/// Errors for add_file_member.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AddFileMemberError
+{
+	#[serde(rename="user_error")]
+	UserError
+	{
+		user_error: SharingUserError,
+	},
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	/// The user has reached the rate limit for invitations.
+	#[serde(rename="rate_limit")]
+	RateLimit,
+	/// The custom message did not pass comment permissions checks.
+	#[serde(rename="invalid_comment")]
+	InvalidComment,
+	#[serde(other)]
+	Other,
+}
+
+/// Errors for remove_file_member_2.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RemoveFileMemberError
+{
+	#[serde(rename="user_error")]
+	UserError
+	{
+		user_error: SharingUserError,
+	},
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	/// This member does not have explicit access to the file and therefore cannot be removed.
+	/// The return value is the access that a user might have to the file from a parent folder.
+	#[serde(rename="no_explicit_access")]
+	NoExplicitAccess(MemberAccessLevelResult),
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum FileMemberActionError
+{
+	/// Specified member was not found.
+	#[serde(rename="invalid_member")]
+	InvalidMember,
+	/// User does not have permission to perform this action on this member.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// Specified file was invalid or user does not have access.
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	/// The action cannot be completed because the target member does not have explicit access to the file.
+	/// The return value is the access that the member has to the file from a parent folder.
+	#[serde(rename="no_explicit_access")]
+	NoExplicitAccess(MemberAccessLevelResult),
+	#[serde(other)]
+	Other,
+}
+
+/// Error for list_file_members.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFileMembersError
+{
+	#[serde(rename="user_error")]
+	UserError
+	{
+		user_error: SharingUserError,
+	},
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+/// Error for list_file_members/continue.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFileMembersContinueError
+{
+	#[serde(rename="user_error")]
+	UserError
+	{
+		user_error: SharingUserError,
+	},
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	/// ListFileMembersContinueArg.cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	#[serde(other)]
+	Other,
+}
+
+/// Error results for list_received_files/continue.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFilesContinueError
+{
+	/// User account had a problem.
+	#[serde(rename="user_error")]
+	UserError
+	{
+		user_error: SharingUserError,
+	},
+	/// ListFilesContinueArg.cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFoldersContinueError
+{
+	/// ListFoldersContinueArg.cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum MountFolderError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// Mounting would cause a shared folder to be inside another, which is disallowed.
+	#[serde(rename="inside_shared_folder")]
+	InsideSharedFolder,
+	/// The current user does not have enough space to mount the shared folder.
+	#[serde(rename="insufficient_quota")]
+	InsufficientQuota(InsufficientQuotaAmounts),
+	/// The shared folder is already mounted.
+	#[serde(rename="already_mounted")]
+	AlreadyMounted,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// The shared folder is not mountable. One example where this can occur is when the shared folder
+	/// belongs within a team folder in the user's Dropbox.
+	#[serde(rename="not_mountable")]
+	NotMountable,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum UnmountFolderError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// The shared folder can't be unmounted. One example where this can occur is when the shared folder's
+	/// parent folder is also a shared folder that resides in the current user's Dropbox.
+	#[serde(rename="not_unmountable")]
+	NotUnmountable,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RelinquishFolderMembershipError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// The current user is the owner of the shared folder. Owners cannot relinquish membership to
+	/// their own folders. Try unsharing or transferring ownership first.
+	#[serde(rename="folder_owner")]
+	FolderOwner,
+	/// The shared folder is currently mounted. Unmount the shared folder before relinquishing membership.
+	#[serde(rename="mounted")]
+	Mounted,
+	/// The current user has access to the shared folder via a group. You can't relinquish membership
+	/// to folders shared via groups.
+	#[serde(rename="group_access")]
+	GroupAccess,
+	/// This action cannot be performed on a team shared folder.
+	#[serde(rename="team_folder")]
+	TeamFolder,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// The current user only has inherited access to the shared folder. You can't relinquish
+	/// inherited membership to folders.
+	#[serde(rename="no_explicit_access")]
+	NoExplicitAccess,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum TransferFolderError
+{
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharedFolderAccessError,
+	},
+	/// TransferFolderArg.to_dropbox_id is invalid.
+	#[serde(rename="invalid_dropbox_id")]
+	InvalidDropboxId,
+	/// The new designated owner is not currently a member of the shared folder.
+	#[serde(rename="new_owner_not_a_member")]
+	NewOwnerNotAMember,
+	/// The new designated owner has not added the folder to their Dropbox.
+	#[serde(rename="new_owner_unmounted")]
+	NewOwnerUnmounted,
+	/// The new designated owner's email address is not verified. This functionality is only available
+	/// on accounts with a verified email address.
+	#[serde(rename="new_owner_email_unverified")]
+	NewOwnerEmailUnverified,
+	/// This action cannot be performed on a team shared folder.
+	#[serde(rename="team_folder")]
+	TeamFolder,
+	/// The current user does not have permission to perform this action.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListDocsCursorError
+{
+	#[serde(rename="cursor_error")]
+	CursorError
+	{
+		cursor_error: PaperApiCursorError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PaperApiCursorError
+{
+	/// The provided cursor is expired.
+	#[serde(rename="expired_cursor")]
+	ExpiredCursor,
+	/// The provided cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	/// The provided cursor contains invalid user.
+	#[serde(rename="wrong_user_in_cursor")]
+	WrongUserInCursor,
+	/// Indicates that the cursor has been invalidated. Call the corresponding non-continue endpoint
+	/// to obtain a new cursor.
+	#[serde(rename="reset")]
+	Reset,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum DocLookupError
+{
+	/// Your account does not have permissions to perform this action.
+	#[serde(rename="insufficient_permissions")]
+	InsufficientPermissions,
+	/// The required doc was not found.
+	#[serde(rename="doc_not_found")]
+	DocNotFound,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PaperDocCreateError
+{
+	/// Your account does not have permissions to perform this action.
+	#[serde(rename="insufficient_permissions")]
+	InsufficientPermissions,
+	/// The provided content was malformed and cannot be imported to Paper.
+	#[serde(rename="content_malformed")]
+	ContentMalformed,
+	/// The specified Paper folder is cannot be found.
+	#[serde(rename="folder_not_found")]
+	FolderNotFound,
+	/// The newly created Paper doc would be too large. Please split the content into multiple docs.
+	#[serde(rename="doc_length_exceeded")]
+	DocLengthExceeded,
+	/// The imported document contains an image that is too large. The current limit is 1MB.
+	/// This only applies to HTML with data URI.
+	#[serde(rename="image_size_exceeded")]
+	ImageSizeExceeded,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PaperDocUpdateError
+{
+	/// Your account does not have permissions to perform this action.
+	#[serde(rename="insufficient_permissions")]
+	InsufficientPermissions,
+	/// The provided content was malformed and cannot be imported to Paper.
+	#[serde(rename="content_malformed")]
+	ContentMalformed,
+	/// The provided revision does not match the document head.
+	#[serde(rename="revision_mismatch")]
+	RevisionMismatch,
+	/// The newly created Paper doc would be too large, split the content into multiple docs.
+	#[serde(rename="doc_length_exceeded")]
+	DocLengthExceeded,
+	/// The imported document contains an image that is too large. The current limit is 1MB.
+	/// This only applies to HTML with data URI.
+	#[serde(rename="image_size_exceeded")]
+	ImageSizeExceeded,
+	/// This operation is not allowed on archived Paper docs.
+	#[serde(rename="doc_archived")]
+	DocArchived,
+	/// This operation is not allowed on deleted Paper docs.
+	#[serde(rename="doc_deleted")]
+	DocDeleted,
+	/// The required doc was not found.
+	#[serde(rename="doc_not_found")]
+	DocNotFound,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListUsersCursorError
+{
+	/// Your account does not have permissions to perform this action.
+	#[serde(rename="insufficient_permissions")]
+	InsufficientPermissions,
+	/// The required doc was not found.
+	#[serde(rename="doc_not_found")]
+	DocNotFound,
+	#[serde(rename="cursor_error")]
+	CursorError
+	{
+		cursor_error: PaperApiCursorError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+/// There is an error accessing the file requests functionality.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum GeneralFileRequestsError
+{
+	/// This user's Dropbox Business team doesn't allow file requests.
+	#[serde(rename="disabled_for_team")]
+	DisabledForTeam,
+	#[serde(other)]
+	Other,
+}
+
+/// There was an error retrieving the file requests.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFileRequestsContinueError
+{
+	/// This user's Dropbox Business team doesn't allow file requests.
+	#[serde(rename="disabled_for_team")]
+	DisabledForTeam,
+	/// The cursor is invalid.
+	#[serde(rename="invalid_cursor")]
+	InvalidCursor,
+	#[serde(other)]
+	Other,
+}
+
+/// There is an error with the file request. Returned by get, update and delete_all_closed.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum FileRequestError
+{
+	/// This user's Dropbox Business team doesn't allow file requests.
+	#[serde(rename="disabled_for_team")]
+	DisabledForTeam,
+	/// This file request ID was not found.
+	#[serde(rename="not_found")]
+	NotFound,
+	/// The specified path is not a folder.
+	#[serde(rename="not_a_folder")]
+	NotAFolder,
+	/// This file request is not accessible to this app. Apps with the app folder permission
+	/// can only access file requests in their app folder.
+	#[serde(rename="app_lacks_access")]
+	AppLacksAccess,
+	/// This user doesn't have permission to access or modify this file request.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// This user's email address is not verified. File requests are only available on accounts
+	/// with a verified email address. Users can verify their email address here.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// There was an error validating the request. For example, the title was invalid,
+	/// or there were disallowed characters in the destination path.
+	#[serde(rename="validation_error")]
+	ValidationError,
+	#[serde(other)]
+	Other,
+}
+
+/// There was an error creating the file request.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum CreateFileRequestError
+{
+	/// This user's Dropbox Business team doesn't allow file requests.
+	#[serde(rename="disabled_for_team")]
+	DisabledForTeam,
+	/// This file request ID was not found.
+	#[serde(rename="not_found")]
+	NotFound,
+	/// The specified path is not a folder.
+	#[serde(rename="not_a_folder")]
+	NotAFolder,
+	/// This file request is not accessible to this app. Apps with the app folder permission
+	/// can only access file requests in their app folder.
+	#[serde(rename="app_lacks_access")]
+	AppLacksAccess,
+	/// This user doesn't have permission to access or modify this file request.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// This user's email address is not verified. File requests are only available on accounts
+	/// with a verified email address. Users can verify their email address here.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// There was an error validating the request. For example, the title was invalid,
+	/// or there were disallowed characters in the destination path.
+	#[serde(rename="validation_error")]
+	ValidationError,
+	/// File requests are not available on the specified folder.
+	#[serde(rename="invalid_location")]
+	InvalidLocation,
+	/// The user has reached the rate limit for creating file requests. The limit is currently
+	/// 4000 file requests total.
+	#[serde(rename="rate_limit")]
+	RateLimit,
+	#[serde(other)]
+	Other,
+}
+
+/// There was an error deleting these file requests.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum DeleteFileRequestError
+{
+	/// This user's Dropbox Business team doesn't allow file requests.
+	#[serde(rename="disabled_for_team")]
+	DisabledForTeam,
+	/// This file request ID was not found.
+	#[serde(rename="not_found")]
+	NotFound,
+	/// The specified path is not a folder.
+	#[serde(rename="not_a_folder")]
+	NotAFolder,
+	/// This file request is not accessible to this app. Apps with the app folder permission
+	/// can only access file requests in their app folder.
+	#[serde(rename="app_lacks_access")]
+	AppLacksAccess,
+	/// This user doesn't have permission to access or modify this file request.
+	#[serde(rename="no_permission")]
+	NoPermission,
+	/// This user's email address is not verified. File requests are only available on accounts
+	/// with a verified email address. Users can verify their email address here.
+	#[serde(rename="email_unverified")]
+	EmailUnverified,
+	/// There was an error validating the request. For example, the title was invalid,
+	/// or there were disallowed characters in the destination path.
+	#[serde(rename="validation_error")]
+	ValidationError,
+	/// One or more file requests currently open.
+	#[serde(rename="file_request_open")]
+	FileRequestOpen,
+	#[serde(other)]
+	Other,
 }
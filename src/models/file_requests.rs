@@ -0,0 +1,219 @@
+/// A file request for receiving files into the user's Dropbox account.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct FileRequest
+{
+	/// The ID of the file request.
+	pub id: String,
+	/// The URL of the file request.
+	pub url: String,
+	/// The title of the file request.
+	pub title: String,
+	/// When this file request was created.
+	pub created: String,
+	/// Whether or not the file request is open. If the file request is closed, it will not accept any more file submissions.
+	pub is_open: bool,
+	/// The number of files this file request has received.
+	pub file_count: i64,
+	/// The path of the folder in the Dropbox where uploaded files will be sent. This can be None
+	/// if the destination was removed. For apps with the app folder permission, this will be relative
+	/// to the app folder. This field is optional.
+	pub destination: Option<String>,
+	/// The deadline for this file request. Only set if the request has a deadline. This field is optional.
+	pub deadline: Option<FileRequestDeadline>,
+	/// A description of the file request. This field is optional.
+	pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct FileRequestDeadline
+{
+	/// The deadline for this file request.
+	pub deadline: String,
+	/// If set, allow uploads after the deadline has passed. These uploads will be marked overdue.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub allow_late_uploads: Option<GracePeriod>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum GracePeriod
+{
+	#[serde(rename="one_day")]
+	OneDay,
+	#[serde(rename="two_days")]
+	TwoDays,
+	#[serde(rename="seven_days")]
+	SevenDays,
+	#[serde(rename="thirty_days")]
+	ThirtyDays,
+	#[serde(rename="always")]
+	Always,
+	/// A grace period unknown to this version, which can't be sent back to Dropbox.
+	#[serde(other, skip_serializing)]
+	Other,
+}
+
+/// Arguments for create.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CreateFileRequestArgs
+{
+	/// The title of the file request. Must not be empty.
+	pub title: String,
+	/// The path of the folder in the Dropbox where uploaded files will be sent.
+	/// For apps with the app folder permission, this will be relative to the app folder.
+	pub destination: String,
+	/// The deadline for the file request. Deadlines can only be set by Professional and Business accounts.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub deadline: Option<FileRequestDeadline>,
+	/// Whether or not the file request should be open. If the file request is closed,
+	/// it will not accept any file submissions, but it can be opened later.
+	/// The default for this field is True.
+	pub open: bool,
+	/// A description of the file request. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub description: Option<String>,
+}
+
+impl Default for CreateFileRequestArgs
+{
+	fn default()
+	-> CreateFileRequestArgs
+	{
+		CreateFileRequestArgs
+		{
+			title: String::new(),
+			destination: String::new(),
+			deadline: None,
+			open: true,
+			description: None,
+		}
+	}
+}
+
+/// Arguments for get.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GetFileRequestArgs
+{
+	/// The ID of the file request to retrieve.
+	pub id: String,
+}
+
+/// Arguments for list_v2.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFileRequestsArg
+{
+	/// The maximum number of file requests that should be returned per request.
+	/// The default for this field is 1000.
+	pub limit: u64,
+}
+
+impl Default for ListFileRequestsArg
+{
+	fn default()
+	-> ListFileRequestsArg
+	{
+		ListFileRequestsArg
+		{
+			limit: 1000,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFileRequestsContinueArg
+{
+	/// The cursor returned by the previous API call specified in the endpoint description.
+	pub cursor: String,
+}
+
+/// Result for list_v2 and list_continue.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFileRequestsV2Result
+{
+	/// The file requests owned by this user. Apps with the app folder permission will only see
+	/// file requests in their app folder.
+	pub file_requests: Vec<FileRequest>,
+	/// Pass the cursor into list_continue to obtain additional file requests.
+	pub cursor: String,
+	/// Is true if there are additional file requests that have not been returned yet.
+	/// An additional call to list_continue can retrieve them.
+	pub has_more: bool,
+}
+
+/// Arguments for update.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UpdateFileRequestArgs
+{
+	/// The ID of the file request to update.
+	pub id: String,
+	/// The new title of the file request. Must not be empty. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub title: Option<String>,
+	/// The new path of the folder in the Dropbox where uploaded files will be sent.
+	/// For apps with the app folder permission, this will be relative to the app folder.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub destination: Option<String>,
+	/// The new deadline for the file request. Deadlines can only be set by Professional and Business accounts.
+	/// The default for this union is no_update.
+	pub deadline: UpdateFileRequestDeadline,
+	/// Whether to set this file request as open or closed. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub open: Option<bool>,
+	/// The description of the file request. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub new_description: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum UpdateFileRequestDeadline
+{
+	/// Do not change the file request's deadline.
+	#[serde(rename="no_update")]
+	#[default]
+	NoUpdate,
+	/// If no deadline is given, the current deadline will be removed.
+	#[serde(rename="update")]
+	Update
+	{
+		#[serde(skip_serializing_if="Option::is_none")]
+		deadline: Option<String>,
+		#[serde(skip_serializing_if="Option::is_none")]
+		allow_late_uploads: Option<GracePeriod>,
+	},
+}
+
+/// Arguments for delete.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct DeleteFileRequestArgs
+{
+	/// List IDs of the file requests to delete.
+	pub ids: Vec<String>,
+}
+
+/// Result for delete.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DeleteFileRequestsResult
+{
+	/// The file requests deleted by the request.
+	pub file_requests: Vec<FileRequest>,
+}
+
+/// Result for delete_all_closed.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DeleteAllClosedFileRequestsResult
+{
+	/// The file requests deleted for this user.
+	pub file_requests: Vec<FileRequest>,
+}
+
+/// Result for count.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CountFileRequestsResult
+{
+	/// The number file requests owner by this user.
+	pub file_request_count: u64,
+}
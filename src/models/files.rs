@@ -1,4 +1,6 @@
+use ::error::DropboxError;
 use ::models::error::*;
+use ::secret::Secret;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct AlphaGetMetadataArg
@@ -346,19 +348,76 @@ pub struct ListRevisionsArg
 {
 	/// The path to the file you want to see the revisions of.
 	pub path: String,
+	/// Determines the behavior of the API in listing the revisions for a given file path or id.
+	/// The default for this union is path.
+	pub mode: ListRevisionsMode,
 	/// The maximum number of revision entries returned. The default for this field is 10.
 	pub limit: u64,
 }
 
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub enum ListRevisionsMode
+{
+	/// Returns revisions with the same file path as identified by the latest file entry at the given file path or id.
+	#[serde(rename="path")]
+	#[default]
+	Path,
+	/// Returns revisions with the same file id as identified by the latest file entry at the given file path or id.
+	#[serde(rename="id")]
+	Id,
+}
+
 #[derive(Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct ListRevisionsResult
 {
-	/// If the file is deleted.
+	/// If the file identified by the latest revision in the response is either deleted or moved.
 	pub is_deleted: bool,
-	/// The revisions for the file. Only non-delete revisions will show up here.
+	/// The time of deletion if the file was deleted. This field is optional.
+	pub server_deleted: Option<String>,
+	/// The revisions for the file. Only revisions that are not deleted will show up here.
 	pub entries: Vec<FileMetadata>,
 }
 
+/// Revision history of a file as returned by DropboxFiles::history.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileHistory
+{
+	/// If the file identified by the latest revision is either deleted or moved.
+	pub is_deleted: bool,
+	/// The time of deletion if the file was deleted. This field is optional.
+	pub server_deleted: Option<String>,
+	/// The revisions of the file, sorted by server_modified with the newest revision first.
+	pub revisions: Vec<FileMetadata>,
+}
+
+/// Differences between two revisions of a file as returned by DropboxFiles::diff_metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataDiff
+{
+	/// Metadata of the first revision.
+	pub from: FileMetadata,
+	/// Metadata of the second revision.
+	pub to: FileMetadata,
+	/// Every field which differs between the two revisions.
+	pub changes: Vec<MetadataChange>,
+}
+
+/// A single field which differs between two revisions of a file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataChange
+{
+	/// The file has been moved or renamed.
+	Path{ from: Option<String>, to: Option<String> },
+	/// The size of the file changed.
+	Size{ from: u64, to: u64 },
+	/// The content of the file changed. This is determined by the content hash.
+	Content{ from: Option<String>, to: Option<String> },
+	/// The modification time set by the client changed.
+	ClientModified{ from: String, to: String },
+	/// The modification time on Dropbox changed.
+	ServerModified{ from: String, to: String },
+}
+
 #[derive(Serialize, Debug, Clone, Default, PartialEq)]
 pub struct ListFolderArg
 {
@@ -377,6 +436,21 @@ pub struct ListFolderArg
 	/// not that file has any explicit members.
 	/// The default for this field is False.
 	pub include_has_explicit_shared_members: bool,
+	/// A shared link to list the contents of. If the link is password-protected, the password must be provided.
+	/// If this field is present, ListFolderArg.path will be relative to root of the shared link.
+	/// Only non-recursive mode is supported for shared link. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub shared_link: Option<SharedLink>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct SharedLink
+{
+	/// Shared link url.
+	pub url: String,
+	/// Password for the shared link. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub password: Option<Secret>,
 }
 
 #[derive(Deserialize, Debug, Clone, Default, PartialEq)]
@@ -455,6 +529,18 @@ pub struct FileMetadata
 	pub content_hash: Option<String>,
 }
 
+impl FileMetadata
+{
+	/// Whether the file has members which DropboxSharing::list_file_members can list,
+	/// either because it is inside a shared folder or because it was shared explicitly.
+	/// Explicit members are only known if include_has_explicit_shared_members was set.
+	pub fn has_shared_members(&self)
+	-> bool
+	{
+		self.sharing_info.is_some() || self.has_explicit_shared_members == Some(true)
+	}
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub enum MediaInfo
 {
@@ -528,15 +614,13 @@ pub struct VideoMetadata
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct FileSharingInfo
 {
-	/// Additional information if the file has custom properties with the property template specified.
+	/// True if the file or folder is inside a read-only shared folder.
 	pub read_only: bool,
-	/// This flag will only be present if include_has_explicit_shared_members is true in list_folder or get_metadata.
-	/// If this flag is present, it will be true if this file has any explicit shared members.
-	/// This is different from sharing_info in that this could be true in the case where a file has
-	/// explicit members but is not contained within a shared folder.
+	/// ID of shared folder that holds this file. Its members are listed by
+	/// DropboxSharing::list_folder_members.
 	pub parent_shared_folder_id: String,
-	/// A hash of the file content. This field can be used to verify data integrity.
-	/// For more information see our Content hash page.
+	/// The last user who modified the file. This field will be null if the user's account has been deleted.
+	/// This field is optional.
 	pub modified_by: Option<String>,
 }
 
@@ -552,6 +636,100 @@ pub struct PropertyGroup
 	pub fields: Vec<PropertyField>,
 }
 
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PropertiesSearchArg
+{
+	/// Queries to search.
+	pub queries: Vec<PropertiesSearchQuery>,
+	/// Filter results to contain only properties associated with these template IDs.
+	/// The default for this union is filter_none.
+	pub template_filter: TemplateFilter,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PropertiesSearchQuery
+{
+	/// The property field value for which to search across templates.
+	pub query: String,
+	/// The mode with which to perform the search.
+	pub mode: PropertiesSearchMode,
+	/// The logical operator with which to append the query. The default for this union is or_operator.
+	pub logical_operator: LogicalOperator,
+}
+
+/// This datatype comes from an imported namespace originally defined in the file_properties namespace.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PropertiesSearchMode
+{
+	/// Search for a value associated with this field name.
+	#[serde(rename="field_name")]
+	FieldName{ field_name: String },
+}
+
+/// This datatype comes from an imported namespace originally defined in the file_properties namespace.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum LogicalOperator
+{
+	/// Append a query with an "or" operator.
+	#[serde(rename="or_operator")]
+	OrOperator,
+}
+
+/// This datatype comes from an imported namespace originally defined in the file_properties namespace.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum TemplateFilter
+{
+	/// Only templates with an ID in the supplied list will be returned (a subset of templates will be returned).
+	#[serde(rename="filter_some")]
+	FilterSome{ filter_some: Vec<String> },
+	/// No templates will be filtered from the result (all templates will be returned).
+	#[serde(rename="filter_none")]
+	FilterNone,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PropertiesSearchResult
+{
+	/// A list (possibly empty) of matches for the query.
+	pub matches: Vec<PropertiesSearchMatch>,
+	/// Pass the cursor into properties/search/continue to continue to receive search results.
+	/// Cursor will be null when there are no more results. This field is optional.
+	pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PropertiesSearchMatch
+{
+	/// The ID for the matched file or folder.
+	pub id: String,
+	/// The path for the matched file or folder.
+	pub path: String,
+	/// Whether the file or folder is deleted.
+	pub is_deleted: bool,
+	/// List of custom property groups associated with the file.
+	pub property_groups: Vec<PropertyGroup>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PropertiesSearchContinueArg
+{
+	/// The cursor returned by your last call to properties/search or properties/search/continue.
+	pub cursor: String,
+}
+
+/// A file or folder found by properties/search together with the property groups that matched.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PropertiesSearchEntry
+{
+	/// Metadata of the matched file or folder.
+	pub metadata: Metadata,
+	/// The property groups of the file or folder which matched the query.
+	pub property_groups: Vec<PropertyGroup>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct FolderMetadata
 {
@@ -576,7 +754,7 @@ pub struct FolderMetadata
 	/// Set if the folder is contained in a shared folder or is a shared folder mount point.
 	pub sharing_info: Option<FolderSharingInfo>,
 	/// Additional information if the file has custom properties with the property template specified.
-	pub property_groups: Option<PropertyGroup>,
+	pub property_groups: Option<Vec<PropertyGroup>>,
 }
 
 /// Sharing info for a folder which is contained in a shared folder or is a shared folder mount point.
@@ -639,6 +817,75 @@ pub struct RestoreArg
 	pub rev: String,
 }
 
+/// Progress and result of DropboxFiles::purge_deleted.
+#[derive(Debug, Default)]
+pub struct PurgeReport
+{
+	/// The number of deleted entries found.
+	pub total: usize,
+	/// Paths which have been permanently deleted.
+	pub purged: Vec<String>,
+	/// Paths which were deleted too recently or whose deletion time is unknown.
+	pub skipped: Vec<String>,
+	/// Paths whose revisions could not be listed or which could not be permanently deleted.
+	pub failed: Vec<PurgeFailure>,
+}
+
+#[derive(Debug)]
+pub struct PurgeFailure
+{
+	/// The path which could not be permanently deleted.
+	pub path: String,
+	/// The error returned by list_revisions or permanently_delete.
+	pub error: DropboxError,
+}
+
+/// Report of DropboxFiles::restore_folder_at.
+#[derive(Debug, Default)]
+pub struct FolderRestoreReport
+{
+	/// The folder which was restored.
+	pub path: String,
+	/// The point in time the folder was restored to.
+	pub timestamp: String,
+	/// If true, the actions were only planned but not executed.
+	pub dry_run: bool,
+	/// Every action needed to bring the folder back to its state at the timestamp.
+	pub entries: Vec<FolderRestoreEntry>,
+	/// Files with more than 100 revisions newer than the timestamp. Their revision at the timestamp
+	/// can't be determined, so they are left untouched.
+	pub skipped: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct FolderRestoreEntry
+{
+	/// The action to take.
+	pub action: FolderRestoreAction,
+	/// Whether the action was executed.
+	pub status: FolderRestoreStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum FolderRestoreAction
+{
+	/// Re-create a folder which was deleted after the timestamp.
+	CreateFolder{ path: String },
+	/// Restore a file to the revision which was current at the timestamp.
+	RestoreFile{ path: String, rev: String },
+}
+
+#[derive(Debug)]
+pub enum FolderRestoreStatus
+{
+	/// The action was only planned, because this was a dry run.
+	Planned,
+	/// The action was executed successfully.
+	Done,
+	/// The action failed with the contained error.
+	Failed(DropboxError),
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct SaveUrlArg
 {
@@ -673,6 +920,65 @@ pub enum SaveUrlJobStatus
 	Failed(SaveUrlError),
 }
 
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct AddTagArg
+{
+	/// Path to the item to be tagged.
+	pub path: String,
+	/// The value of the tag to add. Will be automatically converted to lowercase letters.
+	pub tag_text: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GetTagsArg
+{
+	/// Path to the items.
+	pub paths: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct GetTagsResult
+{
+	/// List of paths and their corresponding tags.
+	pub paths_to_tags: Vec<PathToTags>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PathToTags
+{
+	/// Path of the item.
+	pub path: String,
+	/// Tags assigned to this item.
+	pub tags: Vec<Tag>,
+}
+
+/// Tag that can be added in multiple ways.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum Tag
+{
+	/// Tag generated by the user.
+	#[serde(rename="user_generated_tag")]
+	UserGeneratedTag(UserGeneratedTag),
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UserGeneratedTag
+{
+	pub tag_text: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RemoveTagArg
+{
+	/// Path to the item to tag.
+	pub path: String,
+	/// The tag to remove. Will be automatically converted to lowercase letters.
+	pub tag_text: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SearchArg
 {
@@ -1,5 +1,8 @@
 pub mod auth;
+pub mod check;
+pub mod common;
 pub mod error;
+pub mod file_requests;
 pub mod files;
 pub mod paper;
 pub mod sharing;
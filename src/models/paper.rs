@@ -0,0 +1,565 @@
+use ::models::sharing::{InviteeInfo, MemberSelector, UserInfo};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListPaperDocsArgs
+{
+	/// Allows user to specify how the Paper docs should be filtered.
+	/// The default for this union is docs_accessed.
+	pub filter_by: ListPaperDocsFilterBy,
+	/// Allows user to specify how the Paper docs should be sorted.
+	/// The default for this union is accessed.
+	pub sort_by: ListPaperDocsSortBy,
+	/// Allows user to specify the sort order of the result.
+	/// The default for this union is ascending.
+	pub sort_order: ListPaperDocsSortOrder,
+	/// Size limit per batch. The maximum number of docs that can be retrieved per batch is 1000.
+	/// Higher value results in invalid arguments error. The default for this field is 1000.
+	pub limit: i32,
+}
+
+impl Default for ListPaperDocsArgs
+{
+	fn default()
+	-> ListPaperDocsArgs
+	{
+		ListPaperDocsArgs
+		{
+			filter_by: ListPaperDocsFilterBy::default(),
+			sort_by: ListPaperDocsSortBy::default(),
+			sort_order: ListPaperDocsSortOrder::default(),
+			limit: 1000,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListPaperDocsFilterBy
+{
+	/// Fetches all Paper doc IDs that the user has ever accessed.
+	#[serde(rename="docs_accessed")]
+	#[default]
+	DocsAccessed,
+	/// Fetches only the Paper doc IDs that the user has created.
+	#[serde(rename="docs_created")]
+	DocsCreated,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListPaperDocsSortBy
+{
+	/// Sorts the Paper docs by the time they were last accessed.
+	#[serde(rename="accessed")]
+	#[default]
+	Accessed,
+	/// Sorts the Paper docs by the time they were last modified.
+	#[serde(rename="modified")]
+	Modified,
+	/// Sorts the Paper docs by the creation time.
+	#[serde(rename="created")]
+	Created,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListPaperDocsSortOrder
+{
+	/// Sorts the search result in ascending order.
+	#[serde(rename="ascending")]
+	#[default]
+	Ascending,
+	/// Sorts the search result in descending order.
+	#[serde(rename="descending")]
+	Descending,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListPaperDocsResponse
+{
+	/// The list of Paper doc IDs that can be used to access the given Paper docs or supplied
+	/// to other API methods. The list is sorted in the order specified by the initial call to docs_list.
+	pub doc_ids: Vec<String>,
+	/// Pass the cursor into docs_list_continue to paginate through all files.
+	/// The cursor preserves all properties as specified in the original call to docs_list.
+	pub cursor: Cursor,
+	/// Will be set to True if a subsequent call with the provided cursor to docs_list_continue
+	/// returns immediately with some results. If set to False please allow some delay before
+	/// making another call to docs_list_continue.
+	pub has_more: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Cursor
+{
+	/// The actual cursor value.
+	pub value: String,
+	/// Expiration time of value. Some cursors might have expiration time assigned.
+	/// This is a UTC value after which the cursor is no longer valid and the API starts returning an error.
+	/// If cursor expires a new one needs to be obtained and pagination needs to be restarted.
+	/// This field is optional.
+	pub expiration: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListPaperDocsContinueArgs
+{
+	/// The cursor obtained from docs_list or docs_list_continue.
+	/// Allows for pagination.
+	pub cursor: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocExport
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	pub export_format: ExportFormat,
+}
+
+/// The desired export format of the Paper doc.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ExportFormat
+{
+	/// The HTML export format.
+	#[serde(rename="html")]
+	Html,
+	/// The markdown export format.
+	#[serde(rename="markdown")]
+	#[default]
+	Markdown,
+}
+
+/// The result of docs_download, which is returned in the Dropbox-API-Result header.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocExportResult
+{
+	/// The Paper doc owner's email address.
+	pub owner: String,
+	/// The Paper doc title.
+	pub title: String,
+	/// The Paper doc revision. Simply an ever increasing number.
+	pub revision: i64,
+	/// MIME type of the export. This corresponds to ExportFormat specified in the request.
+	pub mime_type: String,
+}
+
+/// The import format of the incoming data.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ImportFormat
+{
+	/// The provided data is interpreted as standard HTML.
+	#[serde(rename="html")]
+	Html,
+	/// The provided data is interpreted as markdown.
+	/// Note: The first line of the provided document will be used as the doc title.
+	#[serde(rename="markdown")]
+	#[default]
+	Markdown,
+	/// The provided data is interpreted as plain text.
+	/// Note: The first line of the provided document will be used as the doc title.
+	#[serde(rename="plain_text")]
+	PlainText,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocCreateArgs
+{
+	/// The format of provided data.
+	pub import_format: ImportFormat,
+	/// The Paper folder ID where the Paper document should be created.
+	/// The API user has to have write access to this folder or error is thrown.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub parent_folder_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocCreateUpdateResult
+{
+	/// Doc ID of the newly created doc.
+	pub doc_id: String,
+	/// The Paper doc revision. Simply an ever increasing number.
+	pub revision: i64,
+	/// The Paper doc title.
+	pub title: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocUpdateArgs
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// The policy used for the current update call.
+	pub doc_update_policy: PaperDocUpdatePolicy,
+	/// The latest doc revision. This value must match the head revision or an error code will be returned.
+	/// This is to prevent colliding writes.
+	pub revision: i64,
+	/// The format of provided data.
+	pub import_format: ImportFormat,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PaperDocUpdatePolicy
+{
+	/// The content will be appended to the doc.
+	#[serde(rename="append")]
+	Append,
+	/// The content will be prepended to the doc.
+	/// Note: the doc title will not be affected.
+	#[serde(rename="prepend")]
+	Prepend,
+	/// The document will be overwitten at the head with the provided content.
+	#[serde(rename="overwrite_all")]
+	#[default]
+	OverwriteAll,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RefPaperDoc
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+}
+
+/// Metadata about Paper folders containing the specififed Paper doc.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct FoldersContainingPaperDoc
+{
+	/// The sharing policy of the folder containing the Paper doc. This field is optional.
+	pub folder_sharing_policy_type: Option<FolderSharingPolicyType>,
+	/// The folder path. If present the first folder is the root folder. This field is optional.
+	pub folders: Option<Vec<Folder>>,
+}
+
+/// The sharing policy of a Paper folder. Note: The sharing policy of subfolders is inherited from the root folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum FolderSharingPolicyType
+{
+	/// Everyone in your team and anyone directly invited can access this folder.
+	#[serde(rename="team")]
+	Team,
+	/// Only people directly invited can access this folder.
+	#[serde(rename="invite_only")]
+	InviteOnly,
+}
+
+/// Data structure representing a Paper folder.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Folder
+{
+	/// Paper folder ID. This ID uniquely identifies the folder.
+	pub id: String,
+	/// Paper folder name.
+	pub name: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AddPaperDocUser
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// User which should be added to the Paper doc. Specify only email address or Dropbox account ID.
+	pub members: Vec<AddMember>,
+	/// A personal message that will be emailed to each successfully added member. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub custom_message: Option<String>,
+	/// Clients should set this to true if no email message shall be sent to added users.
+	/// The default for this field is False.
+	pub quiet: bool,
+}
+
+/// User who should be added to the Paper doc and the permission level they get.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AddMember
+{
+	/// User which should be added to the Paper doc. Specify only email address or Dropbox account ID.
+	pub member: MemberSelector,
+	/// Permission for the user. The default for this union is edit.
+	pub permission_level: RequestedPaperDocPermissionLevel,
+}
+
+/// The permission level a user is added to a Paper doc with.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedPaperDocPermissionLevel
+{
+	/// User will be granted edit permissions.
+	#[serde(rename="edit")]
+	#[default]
+	Edit,
+	/// User will be granted view and comment permissions.
+	#[serde(rename="view_and_comment")]
+	ViewAndComment,
+}
+
+/// The permission level a user or invitee has on a Paper doc.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum PaperDocPermissionLevel
+{
+	/// User has edit permissions.
+	#[serde(rename="edit")]
+	Edit,
+	/// User has view and comment permissions.
+	#[serde(rename="view_and_comment")]
+	ViewAndComment,
+	#[serde(other)]
+	Other,
+}
+
+/// Per-member result for docs_users_add.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct AddPaperDocUserMemberResult
+{
+	/// One of specified input members.
+	pub member: MemberSelector,
+	/// The outcome of the action on this member.
+	pub result: AddPaperDocUserResult,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AddPaperDocUserResult
+{
+	/// User was successfully added to the Paper doc.
+	#[serde(rename="success")]
+	Success,
+	/// Something unexpected happened when trying to add the user to the Paper doc.
+	#[serde(rename="unknown_error")]
+	UnknownError,
+	/// The Paper doc can be shared only with team members.
+	#[serde(rename="sharing_outside_team_disabled")]
+	SharingOutsideTeamDisabled,
+	/// The daily limit of how many users can be added to the Paper doc was reached.
+	#[serde(rename="daily_limit_reached")]
+	DailyLimitReached,
+	/// Owner's permissions cannot be changed.
+	#[serde(rename="user_is_owner")]
+	UserIsOwner,
+	/// User data could not be retrieved. Clients should retry.
+	#[serde(rename="failed_user_data_retrieval")]
+	FailedUserDataRetrieval,
+	/// This user already has the correct permission to the Paper doc.
+	#[serde(rename="permission_already_granted")]
+	PermissionAlreadyGranted,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListUsersOnPaperDocArgs
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// Size limit per batch. The maximum number of users that can be retrieved per batch is 1000.
+	/// Higher value results in invalid arguments error. The default for this field is 1000.
+	pub limit: i32,
+	/// Specify this attribute if you want to obtain users that have already accessed the Paper doc.
+	/// The default for this union is shared.
+	pub filter_by: UserOnPaperDocFilter,
+}
+
+impl Default for ListUsersOnPaperDocArgs
+{
+	fn default()
+	-> ListUsersOnPaperDocArgs
+	{
+		ListUsersOnPaperDocArgs
+		{
+			doc_id: String::new(),
+			limit: 1000,
+			filter_by: UserOnPaperDocFilter::default(),
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(tag=".tag")]
+pub enum UserOnPaperDocFilter
+{
+	/// All users who have visited the Paper doc.
+	#[serde(rename="visited")]
+	Visited,
+	/// All uses who are shared on the Paper doc. This includes all users who have visited the Paper doc
+	/// as well as those who have not.
+	#[serde(rename="shared")]
+	#[default]
+	Shared,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListUsersOnPaperDocContinueArgs
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// The cursor obtained from docs_users_list or docs_users_list_continue. Allows for pagination.
+	pub cursor: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListUsersOnPaperDocResponse
+{
+	/// List of email addresses with their respective permission levels that are invited on the Paper doc.
+	pub invitees: Vec<InviteeInfoWithPermissionLevel>,
+	/// List of users with their respective permission levels that are invited on the Paper folder.
+	pub users: Vec<UserInfoWithPermissionLevel>,
+	/// The Paper doc owner. This field is populated on every single response.
+	pub doc_owner: UserInfo,
+	/// Pass the cursor into docs_users_list_continue to paginate through all users.
+	pub cursor: Cursor,
+	/// Will be set to True if a subsequent call with the provided cursor to docs_users_list_continue
+	/// returns immediately with some results.
+	pub has_more: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct InviteeInfoWithPermissionLevel
+{
+	/// Email address invited to the Paper doc.
+	pub invitee: InviteeInfo,
+	/// Permission level for the invitee.
+	pub permission_level: PaperDocPermissionLevel,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct UserInfoWithPermissionLevel
+{
+	/// User shared on the Paper doc.
+	pub user: UserInfo,
+	/// Permission level for the user.
+	pub permission_level: PaperDocPermissionLevel,
+}
+
+/// A user or invitee of a Paper doc, as returned by DropboxPaper::docs_users_list_iter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaperDocMember
+{
+	User(UserInfoWithPermissionLevel),
+	Invitee(InviteeInfoWithPermissionLevel),
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RemovePaperDocUser
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// User which should be removed from the Paper doc. Specify only email address or Dropbox account ID.
+	pub member: MemberSelector,
+}
+
+/// Sharing policy of Paper doc.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SharingPolicy
+{
+	/// This value applies to the non-team members. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub public_sharing_policy: Option<SharingPublicPolicyType>,
+	/// This value applies to the team members only. The value is null for all personal accounts.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub team_sharing_policy: Option<SharingTeamPolicyType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharingPublicPolicyType
+{
+	/// Users who have a link to this doc can edit it.
+	#[serde(rename="people_with_link_can_edit")]
+	PeopleWithLinkCanEdit,
+	/// Users who have a link to this doc can view and comment on it.
+	#[serde(rename="people_with_link_can_view_and_comment")]
+	PeopleWithLinkCanViewAndComment,
+	/// Users must be explicitly invited to this doc.
+	#[serde(rename="invite_only")]
+	InviteOnly,
+	/// Value used to indicate that doc sharing is enabled only within team.
+	#[serde(rename="disabled")]
+	Disabled,
+}
+
+/// The sharing policy type of the Paper doc.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharingTeamPolicyType
+{
+	/// Users who have a link to this doc can edit it.
+	#[serde(rename="people_with_link_can_edit")]
+	PeopleWithLinkCanEdit,
+	/// Users who have a link to this doc can view and comment on it.
+	#[serde(rename="people_with_link_can_view_and_comment")]
+	PeopleWithLinkCanViewAndComment,
+	/// Users must be explicitly invited to this doc.
+	#[serde(rename="invite_only")]
+	InviteOnly,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PaperDocSharingPolicy
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// The default sharing policy to be set for the Paper doc.
+	pub sharing_policy: SharingPolicy,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListUsersOnFolderArgs
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// Size limit per batch. The maximum number of users that can be retrieved per batch is 1000.
+	/// Higher value results in invalid arguments error. The default for this field is 1000.
+	pub limit: i32,
+}
+
+impl Default for ListUsersOnFolderArgs
+{
+	fn default()
+	-> ListUsersOnFolderArgs
+	{
+		ListUsersOnFolderArgs
+		{
+			doc_id: String::new(),
+			limit: 1000,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListUsersOnFolderContinueArgs
+{
+	/// The Paper doc ID.
+	pub doc_id: String,
+	/// The cursor obtained from docs_folder_users_list or docs_folder_users_list_continue.
+	/// Allows for pagination.
+	pub cursor: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListUsersOnFolderResponse
+{
+	/// List of email addresses that are invited on the Paper folder.
+	pub invitees: Vec<InviteeInfo>,
+	/// List of users that are invited on the Paper folder.
+	pub users: Vec<UserInfo>,
+	/// Pass the cursor into docs_folder_users_list_continue to paginate through all users.
+	pub cursor: Cursor,
+	/// Will be set to True if a subsequent call with the provided cursor to docs_folder_users_list_continue
+	/// returns immediately with some results.
+	pub has_more: bool,
+}
+
+/// A user or invitee of the folder containing a Paper doc, as returned by
+/// DropboxPaper::docs_folder_users_list_iter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaperFolderMember
+{
+	User(UserInfo),
+	Invitee(InviteeInfo),
+}
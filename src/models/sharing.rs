@@ -0,0 +1,1397 @@
+use ::models::users::{SharedFolderMemberPolicy, SharedLinkCreatePolicy};
+use ::models::error::*;
+use ::secret::Secret;
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct CreateSharedLinkWithSettingsArg
+{
+	/// The path to be shared by the shared link.
+	pub path: String,
+	/// The requested settings for the newly created shared link. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub settings: Option<SharedLinkSettings>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct SharedLinkSettings
+{
+	/// The requested access for this shared link. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub requested_visibility: Option<RequestedVisibility>,
+	/// If requested_visibility is RequestedVisibility.password this is needed to specify the password
+	/// to access the link. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub link_password: Option<Secret>,
+	/// Expiration time of the shared link. By default the link won't expire. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub expires: Option<String>,
+	/// The new audience who can benefit from the access level specified by the link's access level
+	/// specified in the link_access_level field of LinkPermissions. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub audience: Option<RequestedLinkAudience>,
+	/// Requested access level you want the audience to gain from this link.
+	/// Note, modifying access level for an existing link is not supported. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub access: Option<RequestedLinkAccessLevel>,
+}
+
+/// The access permission that can be requested by the caller for the shared link.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedVisibility
+{
+	/// Anyone who has received the link can access it. No login required.
+	#[serde(rename="public")]
+	Public,
+	/// Only members of the same team can access the link. Login is required.
+	#[serde(rename="team_only")]
+	TeamOnly,
+	/// A link-specific password is required to access the link. Login is not required.
+	#[serde(rename="password")]
+	Password,
+}
+
+/// The actual access permissions values of shared links after taking into account user preferences
+/// and the team and shared folder settings.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ResolvedVisibility
+{
+	/// Anyone who has received the link can access it. No login required.
+	#[serde(rename="public")]
+	Public,
+	/// Only members of the same team can access the link. Login is required.
+	#[serde(rename="team_only")]
+	TeamOnly,
+	/// A link-specific password is required to access the link. Login is not required.
+	#[serde(rename="password")]
+	Password,
+	/// Only members of the same team who have the link-specific password can access the link.
+	#[serde(rename="team_and_password")]
+	TeamAndPassword,
+	/// Only members of the shared folder containing the linked file can access the link.
+	#[serde(rename="shared_folder_only")]
+	SharedFolderOnly,
+	/// The link merely points the user to the content, and does not grant any additional rights.
+	#[serde(rename="no_one")]
+	NoOne,
+	/// Only the current user can view this link.
+	#[serde(rename="only_you")]
+	OnlyYou,
+	#[serde(other)]
+	Other,
+}
+
+/// Who should benefit from the access level of a new or modified shared link.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedLinkAudience
+{
+	/// Link is accessible by anyone.
+	#[serde(rename="public")]
+	Public,
+	/// Link is accessible only by team members.
+	#[serde(rename="team")]
+	Team,
+	/// The link can be used by no one. The link merely points the user to the content,
+	/// and does not grant additional rights to the user.
+	#[serde(rename="no_one")]
+	NoOne,
+	/// Link is accessible only by members of the content.
+	#[serde(rename="members")]
+	Members,
+}
+
+/// Who can benefit from the access level of a shared link.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum LinkAudience
+{
+	/// Link is accessible by anyone.
+	#[serde(rename="public")]
+	Public,
+	/// Link is accessible only by team members.
+	#[serde(rename="team")]
+	Team,
+	/// The link can be used by no one. The link merely points the user to the content,
+	/// and does not grant additional rights to the user.
+	#[serde(rename="no_one")]
+	NoOne,
+	/// Link is accessible only by members of the content.
+	#[serde(rename="members")]
+	Members,
+	#[serde(other)]
+	Other,
+}
+
+/// The access level a shared link grants.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum LinkAccessLevel
+{
+	/// Users who use the link can view and comment on the content.
+	#[serde(rename="viewer")]
+	Viewer,
+	/// Users who use the link can edit, view and comment on the content.
+	#[serde(rename="editor")]
+	Editor,
+	#[serde(other)]
+	Other,
+}
+
+/// The access level requested for a new shared link.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedLinkAccessLevel
+{
+	/// Users who use the link can view and comment on the content.
+	#[serde(rename="viewer")]
+	Viewer,
+	/// Users who use the link can edit, view and comment on the content.
+	/// Note not all file types support edit links yet.
+	#[serde(rename="editor")]
+	Editor,
+	/// Request for the maximum access level you can set the link to.
+	#[serde(rename="max")]
+	Max,
+	/// Request for the default access level the user has set.
+	#[serde(rename="default")]
+	Default,
+}
+
+/// The metadata of a shared link. This datatype will be one of the following subtypes:
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedLinkMetadata
+{
+	#[serde(rename="file")]
+	File(FileLinkMetadata),
+	#[serde(rename="folder")]
+	Folder(FolderLinkMetadata),
+}
+
+impl SharedLinkMetadata
+{
+	/// URL of the shared link.
+	pub fn url(&self)
+	-> &str
+	{
+		match *self
+		{
+			SharedLinkMetadata::File(ref r) => &r.url,
+			SharedLinkMetadata::Folder(ref r) => &r.url,
+		}
+	}
+
+	/// The linked file or folder's lowercased full path in the user's Dropbox,
+	/// if the linked file or folder is in the user's Dropbox.
+	pub fn path_lower(&self)
+	-> Option<&str>
+	{
+		match *self
+		{
+			SharedLinkMetadata::File(ref r) => r.path_lower.as_deref(),
+			SharedLinkMetadata::Folder(ref r) => r.path_lower.as_deref(),
+		}
+	}
+
+	/// The link's access permissions.
+	pub fn link_permissions(&self)
+	-> &LinkPermissions
+	{
+		match *self
+		{
+			SharedLinkMetadata::File(ref r) => &r.link_permissions,
+			SharedLinkMetadata::Folder(ref r) => &r.link_permissions,
+		}
+	}
+
+	/// Expiration time, if set.
+	pub fn expires(&self)
+	-> Option<&str>
+	{
+		match *self
+		{
+			SharedLinkMetadata::File(ref r) => r.expires.as_deref(),
+			SharedLinkMetadata::Folder(ref r) => r.expires.as_deref(),
+		}
+	}
+}
+
+/// The metadata of a file shared link.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FileLinkMetadata
+{
+	/// URL of the shared link.
+	pub url: String,
+	/// The linked file name (including extension). This never contains a slash.
+	pub name: String,
+	/// The link's access permissions.
+	pub link_permissions: LinkPermissions,
+	/// The modification time set by the desktop client when the file was added to Dropbox.
+	pub client_modified: String,
+	/// The last time the file was modified on Dropbox.
+	pub server_modified: String,
+	/// A unique identifier for the current revision of a file.
+	pub rev: String,
+	/// The file size in bytes.
+	pub size: u64,
+	/// A unique identifier for the linked file. This field is optional.
+	pub id: Option<String>,
+	/// Expiration time, if set. By default the link won't expire. This field is optional.
+	pub expires: Option<String>,
+	/// The lowercased full path in the user's Dropbox. This always starts with a slash.
+	/// This field will only be present only if the linked file is in the authenticated user's dropbox.
+	pub path_lower: Option<String>,
+}
+
+/// The metadata of a folder shared link.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FolderLinkMetadata
+{
+	/// URL of the shared link.
+	pub url: String,
+	/// The linked file name (including extension). This never contains a slash.
+	pub name: String,
+	/// The link's access permissions.
+	pub link_permissions: LinkPermissions,
+	/// A unique identifier for the linked file. This field is optional.
+	pub id: Option<String>,
+	/// Expiration time, if set. By default the link won't expire. This field is optional.
+	pub expires: Option<String>,
+	/// The lowercased full path in the user's Dropbox. This always starts with a slash.
+	/// This field will only be present only if the linked file is in the authenticated user's dropbox.
+	pub path_lower: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct LinkPermissions
+{
+	/// Whether the caller can revoke the shared link.
+	pub can_revoke: bool,
+	/// The current visibility of the link after considering the shared links policies of the team
+	/// (in case the link's owner is part of a team) and the shared folder (in case the linked file
+	/// is part of a shared folder). This field is optional.
+	pub resolved_visibility: Option<ResolvedVisibility>,
+	/// The shared link's requested visibility. This can be overridden by the team and shared folder policies.
+	/// This field is optional.
+	pub requested_visibility: Option<RequestedVisibility>,
+	/// The type of audience who can benefit from the access level specified by the link_access_level field.
+	/// This field is optional.
+	pub effective_audience: Option<LinkAudience>,
+	/// The access level that the link will grant to its users. This field is optional.
+	pub link_access_level: Option<LinkAccessLevel>,
+	/// Whether the link allows its users to download the content. The default for this field is False.
+	#[serde(default)]
+	pub allow_download: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListSharedLinksArg
+{
+	/// See list_shared_links description. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub path: Option<String>,
+	/// The cursor returned by your last call to list_shared_links. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub cursor: Option<String>,
+	/// See list_shared_links description. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub direct_only: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListSharedLinksResult
+{
+	/// Shared links applicable to the path argument.
+	pub links: Vec<SharedLinkMetadata>,
+	/// Is true if there are additional shared links that have not been returned yet.
+	/// Pass the cursor into list_shared_links to retrieve them.
+	pub has_more: bool,
+	/// Pass the cursor into list_shared_links to obtain the additional links.
+	/// Cursor is returned only if no path is given. This field is optional.
+	pub cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ModifySharedLinkSettingsArgs
+{
+	/// URL of the shared link to change its settings.
+	pub url: String,
+	/// Set of settings for the shared link.
+	pub settings: SharedLinkSettings,
+	/// If set to true, removes the expiration of the shared link. The default for this field is False.
+	pub remove_expiration: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RevokeSharedLinkArg
+{
+	/// URL of the shared link.
+	pub url: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GetSharedLinkMetadataArg
+{
+	/// URL of the shared link.
+	pub url: String,
+	/// If the shared link is to a folder, this parameter can be used to retrieve the metadata for
+	/// a specific file or sub-folder in this folder. A relative path should be used. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub path: Option<String>,
+	/// If the shared link has a password, this parameter can be used. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub link_password: Option<Secret>,
+}
+
+/// The access level to grant collaborators.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedAccessLevel
+{
+	/// The collaborator is the owner of the shared folder. Owners can view and edit the shared folder
+	/// as well as set the folder's policies using update_folder_policy.
+	#[serde(rename="owner")]
+	Owner,
+	/// The collaborator can both view and edit the shared folder.
+	#[serde(rename="editor")]
+	Editor,
+	/// The collaborator can only view the shared folder.
+	#[serde(rename="viewer")]
+	Viewer,
+	/// The collaborator can only view the shared folder and does not have any access to comments.
+	#[serde(rename="viewer_no_comment")]
+	ViewerNoComment,
+	/// The collaborator can only view the shared folder that they have access to.
+	#[serde(rename="traverse")]
+	Traverse,
+	/// If there is a Righteous Link on the folder which grants access and the user has visited such link,
+	/// they are allowed to perform certain action (i.e. add themselves to the folder) via the link access
+	/// even though the user themselves are not a member on the shared folder yet.
+	#[serde(rename="no_access")]
+	NoAccess,
+}
+
+/// Defines the access levels collaborators have. Serializable for access reports only.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AccessLevel
+{
+	/// The collaborator is the owner of the shared folder. Owners can view and edit the shared folder
+	/// as well as set the folder's policies using update_folder_policy.
+	#[serde(rename="owner")]
+	Owner,
+	/// The collaborator can both view and edit the shared folder.
+	#[serde(rename="editor")]
+	Editor,
+	/// The collaborator can only view the shared folder.
+	#[serde(rename="viewer")]
+	Viewer,
+	/// The collaborator can only view the shared folder and does not have any access to comments.
+	#[serde(rename="viewer_no_comment")]
+	ViewerNoComment,
+	/// The collaborator can only view the shared folder that they have access to.
+	#[serde(rename="traverse")]
+	Traverse,
+	/// If there is a Righteous Link on the folder which grants access and the user has visited such link,
+	/// they are allowed to perform certain action (i.e. add themselves to the folder) via the link access
+	/// even though the user themselves are not a member on the shared folder yet.
+	#[serde(rename="no_access")]
+	NoAccess,
+	#[serde(other)]
+	Other,
+}
+
+/// Includes different ways to identify a member of a shared folder.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum MemberSelector
+{
+	/// Dropbox account, team member, or group ID of member.
+	#[serde(rename="dropbox_id")]
+	DropboxId
+	{
+		dropbox_id: String,
+	},
+	/// Email address of member.
+	#[serde(rename="email")]
+	Email
+	{
+		email: String,
+	},
+}
+
+/// Who should be able to add and remove members from a new shared folder.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedAclUpdatePolicy
+{
+	/// Only the owner can update the ACL.
+	#[serde(rename="owner")]
+	Owner,
+	/// Any editor can update the ACL. This may be further restricted to editors on the same team.
+	#[serde(rename="editors")]
+	Editors,
+}
+
+/// Who can add and remove members from this shared folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum AclUpdatePolicy
+{
+	/// Only the owner can update the ACL.
+	#[serde(rename="owner")]
+	Owner,
+	/// Any editor can update the ACL. This may be further restricted to editors on the same team.
+	#[serde(rename="editors")]
+	Editors,
+	#[serde(other)]
+	Other,
+}
+
+/// Who links to content in a new shared folder should be shareable with.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedSharedLinkPolicy
+{
+	/// Links can be shared with anyone.
+	#[serde(rename="anyone")]
+	Anyone,
+	/// Links can be shared with anyone on the same team as the owner.
+	#[serde(rename="team")]
+	Team,
+	/// Links can only be shared among members of the shared folder.
+	#[serde(rename="members")]
+	Members,
+}
+
+/// Who can view shared links in this folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum SharedLinkPolicy
+{
+	/// Links can be shared with anyone.
+	#[serde(rename="anyone")]
+	Anyone,
+	/// Links can be shared with anyone on the same team as the owner.
+	#[serde(rename="team")]
+	Team,
+	/// Links can only be shared among members of the shared folder.
+	#[serde(rename="members")]
+	Members,
+	#[serde(other)]
+	Other,
+}
+
+/// Whether viewers of a new shared folder should see who has seen the content.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RequestedViewerInfoPolicy
+{
+	/// Viewers are able to see who has opened the file or viewed the folder.
+	#[serde(rename="enabled")]
+	Enabled,
+	/// Viewers are not able to see who has opened the file or viewed the folder.
+	#[serde(rename="disabled")]
+	Disabled,
+}
+
+/// Whether viewers can see who has seen the content.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ViewerInfoPolicy
+{
+	/// Viewers are able to see who has opened the file or viewed the folder.
+	#[serde(rename="enabled")]
+	Enabled,
+	/// Viewers are not able to see who has opened the file or viewed the folder.
+	#[serde(rename="disabled")]
+	Disabled,
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ShareFolderArg
+{
+	/// The path or the file id to the folder to share. If it does not exist, then a new one is created.
+	pub path: String,
+	/// Who can add and remove members of this shared folder. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub acl_update_policy: Option<RequestedAclUpdatePolicy>,
+	/// Whether to force the share to happen asynchronously. The default for this field is False.
+	pub force_async: bool,
+	/// Who can be a member of this shared folder. Only applicable if the current user is on a team.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub member_policy: Option<SharedFolderMemberPolicy>,
+	/// The policy to apply to shared links created for content inside this shared folder.
+	/// The current user must be on a team to set this policy to SharedLinkPolicy.members.
+	/// This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub shared_link_policy: Option<RequestedSharedLinkPolicy>,
+	/// Who can enable/disable viewer info for this shared folder. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub viewer_info_policy: Option<RequestedViewerInfoPolicy>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ShareFolderLaunch
+{
+	/// This response indicates that the processing is asynchronous.
+	/// The string is an id that can be used to obtain the status of the asynchronous job.
+	#[serde(rename="async_job_id")]
+	AsyncJobId
+	{
+		async_job_id: String,
+	},
+	#[serde(rename="complete")]
+	Complete(SharedFolderMetadata),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ShareFolderJobStatus
+{
+	/// The asynchronous job is still in progress.
+	#[serde(rename="in_progress")]
+	InProgress,
+	/// The share job has finished. The value is the metadata for the folder.
+	#[serde(rename="complete")]
+	Complete(SharedFolderMetadata),
+	#[serde(rename="failed")]
+	Failed
+	{
+		failed: ShareFolderError,
+	},
+}
+
+/// The metadata which includes basic information about the shared folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedFolderMetadata
+{
+	/// The current user's access level for this shared folder.
+	pub access_type: AccessLevel,
+	/// Whether this folder is inside of a team folder.
+	pub is_inside_team_folder: bool,
+	/// Whether this folder is a team folder.
+	pub is_team_folder: bool,
+	/// The name of the this shared folder.
+	pub name: String,
+	/// Policies governing this shared folder.
+	pub policy: FolderPolicy,
+	/// URL for displaying a web preview of the shared folder.
+	pub preview_url: String,
+	/// The ID of the shared folder.
+	pub shared_folder_id: String,
+	/// Timestamp indicating when the current user was invited to this shared folder.
+	pub time_invited: String,
+	/// The display names of the users that own the folder. If the folder is part of a team folder,
+	/// the display names of the team admins are also included. Absent if the owner display names
+	/// cannot be fetched. This field is optional.
+	pub owner_display_names: Option<Vec<String>>,
+	/// The ID of the parent shared folder. This field is present only if the folder is contained
+	/// within another shared folder. This field is optional.
+	pub parent_shared_folder_id: Option<String>,
+	/// The lower-cased full path of this shared folder. Absent for unmounted folders. This field is optional.
+	pub path_lower: Option<String>,
+}
+
+/// A set of policies governing membership and privileges for a shared folder.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FolderPolicy
+{
+	/// Who can add and remove members from this shared folder.
+	pub acl_update_policy: AclUpdatePolicy,
+	/// Who links can be shared with.
+	pub shared_link_policy: SharedLinkPolicy,
+	/// Who can be a member of this shared folder, as set on the folder itself.
+	/// The effective policy may differ from this value if the team-wide policy is more restrictive.
+	/// Present only if the folder is owned by a team. This field is optional.
+	pub member_policy: Option<SharedFolderMemberPolicy>,
+	/// Who can be a member of this shared folder, taking into account both the folder and
+	/// the team-wide policy. This value may differ from that of member_policy if the team-wide
+	/// policy is more restrictive than the folder policy. Present only if the folder is owned by a team.
+	/// This field is optional.
+	pub resolved_member_policy: Option<SharedFolderMemberPolicy>,
+	/// Who can enable/disable viewer info for this shared folder. This field is optional.
+	pub viewer_info_policy: Option<ViewerInfoPolicy>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UnshareFolderArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// If true, members of this shared folder will get a copy of this folder after it's unshared.
+	/// Otherwise, it will be removed from their Dropbox. The current user, who is an owner,
+	/// will always retain their copy. The default for this field is False.
+	pub leave_a_copy: bool,
+}
+
+/// Result returned by methods that may either launch an asynchronous job or complete synchronously.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum LaunchEmptyResult
+{
+	/// This response indicates that the processing is asynchronous.
+	/// The string is an id that can be used to obtain the status of the asynchronous job.
+	#[serde(rename="async_job_id")]
+	AsyncJobId
+	{
+		async_job_id: String,
+	},
+	/// The job finished synchronously and successfully.
+	#[serde(rename="complete")]
+	Complete,
+}
+
+/// Result returned by methods that launch an asynchronous job.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum LaunchResultBase
+{
+	/// This response indicates that the processing is asynchronous.
+	/// The string is an id that can be used to obtain the status of the asynchronous job.
+	#[serde(rename="async_job_id")]
+	AsyncJobId
+	{
+		async_job_id: String,
+	},
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum JobStatus
+{
+	/// The asynchronous job is still in progress.
+	#[serde(rename="in_progress")]
+	InProgress,
+	/// The asynchronous job has finished.
+	#[serde(rename="complete")]
+	Complete,
+	/// The asynchronous job returned an error.
+	#[serde(rename="failed")]
+	Failed
+	{
+		failed: JobError,
+	},
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AddFolderMemberArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// The intended list of members to add. Added members will receive invites to join the shared folder.
+	pub members: Vec<AddMember>,
+	/// Whether added members should be notified via email and device notifications of their invite.
+	/// The default for this field is False.
+	pub quiet: bool,
+	/// Optional message to display to added members in their invitation. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub custom_message: Option<String>,
+}
+
+/// The member and type of access the member should have when added to a shared folder.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AddMember
+{
+	/// The member to add to the shared folder.
+	pub member: MemberSelector,
+	/// The access level to grant member to the shared folder. AccessLevel.owner is disallowed.
+	pub access_level: RequestedAccessLevel,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RemoveFolderMemberArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// The member to remove from the folder.
+	pub member: MemberSelector,
+	/// If true, the removed user will keep their copy of the folder after it's unshared,
+	/// assuming it was mounted. Otherwise, it will be removed from their Dropbox.
+	/// This must be set to false when removing a group, or when the folder is within a team folder or another shared folder.
+	pub leave_a_copy: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum RemoveMemberJobStatus
+{
+	/// The asynchronous job is still in progress.
+	#[serde(rename="in_progress")]
+	InProgress,
+	/// Removing the folder member has finished. The value is information about whether the member
+	/// has another form of access.
+	#[serde(rename="complete")]
+	Complete(MemberAccessLevelResult),
+	#[serde(rename="failed")]
+	Failed
+	{
+		failed: RemoveFolderMemberError,
+	},
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct UpdateFolderMemberArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// The member of the shared folder to update. Only the MemberSelector.dropbox_id may be set at this time.
+	pub member: MemberSelector,
+	/// The new access level for member. AccessLevel.owner is disallowed.
+	pub access_level: RequestedAccessLevel,
+}
+
+/// Contains information about a member's access level to content after an operation.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MemberAccessLevelResult
+{
+	/// The member still has this level of access to the content through a parent folder. This field is optional.
+	pub access_level: Option<AccessLevel>,
+	/// A localized string with additional information about why the user has this access level to the content.
+	/// This field is optional.
+	pub warning: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFolderMembersArgs
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// The maximum number of results that include members, groups and invitees to return per request.
+	/// The default for this field is 1000.
+	pub limit: u32,
+}
+
+impl Default for ListFolderMembersArgs
+{
+	fn default()
+	-> ListFolderMembersArgs
+	{
+		ListFolderMembersArgs
+		{
+			shared_folder_id: String::new(),
+			limit: 1000,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFolderMembersContinueArg
+{
+	/// The cursor returned by your last call to list_folder_members or list_folder_members/continue.
+	pub cursor: String,
+}
+
+/// Shared folder user and group membership.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedFolderMembers
+{
+	/// The list of user members of the shared folder.
+	pub users: Vec<UserMembershipInfo>,
+	/// The list of group members of the shared folder.
+	pub groups: Vec<GroupMembershipInfo>,
+	/// The list of invitees to the shared folder.
+	pub invitees: Vec<InviteeMembershipInfo>,
+	/// Present if there are additional shared folder members that have not been returned yet.
+	/// Pass the cursor into list_folder_members/continue to list additional members. This field is optional.
+	pub cursor: Option<String>,
+}
+
+/// The information about a user member of the shared content.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct UserMembershipInfo
+{
+	/// The access type for this member. It contains inherited access type from parent folder,
+	/// and acquired access type from this folder.
+	pub access_type: AccessLevel,
+	/// The account information for the membership user.
+	pub user: UserInfo,
+	/// True if the member has access from a parent folder. The default for this field is False.
+	#[serde(default)]
+	pub is_inherited: bool,
+}
+
+/// Basic information about a user. Use users.get_account and users.get_account_batch
+/// to obtain more detailed information.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct UserInfo
+{
+	/// The account ID of the user.
+	pub account_id: String,
+	/// Email address of user.
+	pub email: String,
+	/// The display name of the user.
+	pub display_name: String,
+	/// If the user is in the same team as current user.
+	pub same_team: bool,
+	/// The team member ID of the shared folder member. Only present if same_team is true. This field is optional.
+	pub team_member_id: Option<String>,
+}
+
+/// The information about a group member of the shared content.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GroupMembershipInfo
+{
+	/// The access type for this member. It contains inherited access type from parent folder,
+	/// and acquired access type from this folder.
+	pub access_type: AccessLevel,
+	/// The information about the membership group.
+	pub group: GroupInfo,
+	/// True if the member has access from a parent folder. The default for this field is False.
+	#[serde(default)]
+	pub is_inherited: bool,
+}
+
+/// The information about a group.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GroupInfo
+{
+	pub group_name: String,
+	pub group_id: String,
+	/// If the current user is a member of the group.
+	pub is_member: bool,
+	/// If the current user is an owner of the group.
+	pub is_owner: bool,
+	/// If the group is owned by the current user's team.
+	pub same_team: bool,
+	/// The number of members in the group. This field is optional.
+	pub member_count: Option<u32>,
+}
+
+/// Information about an invited member of a shared content.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct InviteeMembershipInfo
+{
+	/// The access type for this member. It contains inherited access type from parent folder,
+	/// and acquired access type from this folder.
+	pub access_type: AccessLevel,
+	/// Recipient of the invitation.
+	pub invitee: InviteeInfo,
+	/// The user this invitation is tied to, if available. This field is optional.
+	pub user: Option<UserInfo>,
+	/// True if the member has access from a parent folder. The default for this field is False.
+	#[serde(default)]
+	pub is_inherited: bool,
+}
+
+/// Information about the recipient of a shared content invitation.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum InviteeInfo
+{
+	/// Email address of invited user.
+	#[serde(rename="email")]
+	Email
+	{
+		email: String,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GetMetadataArgs
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AddFileMemberArgs
+{
+	/// File to which to add members.
+	pub file: String,
+	/// Members to add. Note that even an email address is given, this may result in a user being
+	/// directly added to the membership if that email is the user's main account email.
+	pub members: Vec<MemberSelector>,
+	/// Message to send to added members in their invitation. This field is optional.
+	#[serde(skip_serializing_if="Option::is_none")]
+	pub custom_message: Option<String>,
+	/// Whether added members should be notified via device notifications of their invitation.
+	/// The default for this field is False.
+	pub quiet: bool,
+	/// AccessLevel union object, describing what access level we want to give new members.
+	/// The default for this is AccessLevel.viewer.
+	pub access_level: RequestedAccessLevel,
+	/// If the custom message should be added as a comment on the file. The default for this field is False.
+	pub add_message_as_comment: bool,
+}
+
+/// Per-member result for add_file_member.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FileMemberActionResult
+{
+	/// One of specified input members.
+	pub member: MemberSelector,
+	/// The outcome of the action on this member.
+	pub result: FileMemberActionIndividualResult,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum FileMemberActionIndividualResult
+{
+	/// Member was successfully removed from this file. If AccessLevel is given,
+	/// the member still has access via a parent shared folder.
+	#[serde(rename="success")]
+	Success
+	{
+		success: Option<AccessLevel>,
+	},
+	/// User was not able to perform this action.
+	#[serde(rename="member_error")]
+	MemberError
+	{
+		member_error: FileMemberActionError,
+	},
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RemoveFileMemberArg
+{
+	/// File from which to remove members.
+	pub file: String,
+	/// Member to remove from this file. Note that even if an email is specified, it may result in
+	/// the removal of a user (not an invitee) if the user's main account corresponds to that email address.
+	pub member: MemberSelector,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum FileMemberRemoveActionResult
+{
+	/// Member was successfully removed from this file.
+	#[serde(rename="success")]
+	Success(MemberAccessLevelResult),
+	/// User was not able to remove this member.
+	#[serde(rename="member_error")]
+	MemberError
+	{
+		member_error: FileMemberActionError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct UpdateFileMemberArgs
+{
+	/// File for which we are changing a member's access.
+	pub file: String,
+	/// The member whose access we are changing.
+	pub member: MemberSelector,
+	/// The new access level for the member.
+	pub access_level: RequestedAccessLevel,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFileMembersArg
+{
+	/// The file for which you want to see members.
+	pub file: String,
+	/// Whether to include members who only have access from a parent shared folder.
+	/// The default for this field is True.
+	pub include_inherited: bool,
+	/// Number of members to return max per query. Defaults to 100 if no limit is specified.
+	pub limit: u32,
+}
+
+impl Default for ListFileMembersArg
+{
+	fn default()
+	-> ListFileMembersArg
+	{
+		ListFileMembersArg
+		{
+			file: String::new(),
+			include_inherited: true,
+			limit: 100,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFileMembersContinueArg
+{
+	/// The cursor returned by your last call to list_file_members, list_file_members/continue,
+	/// or list_file_members/batch.
+	pub cursor: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFileMembersBatchArg
+{
+	/// Files for which to return members.
+	pub files: Vec<String>,
+	/// Number of members to return max per query. Defaults to 10 if no limit is specified.
+	pub limit: u32,
+}
+
+impl Default for ListFileMembersBatchArg
+{
+	fn default()
+	-> ListFileMembersBatchArg
+	{
+		ListFileMembersBatchArg
+		{
+			files: vec![],
+			limit: 10,
+		}
+	}
+}
+
+/// Per-file result for list_file_members/batch.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListFileMembersBatchResult
+{
+	/// This is the input file identifier, whether an ID or a path.
+	pub file: String,
+	/// The result for this particular file.
+	pub result: ListFileMembersIndividualResult,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
+pub enum ListFileMembersIndividualResult
+{
+	/// The results of the query for this file if it was successful.
+	#[serde(rename="result")]
+	Result(ListFileMembersCountResult),
+	/// The result of the query for this file if it was an error.
+	#[serde(rename="access_error")]
+	AccessError
+	{
+		access_error: SharingFileAccessError,
+	},
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListFileMembersCountResult
+{
+	/// A list of members on this file.
+	pub members: SharedFileMembers,
+	/// The number of members on this file. This does not include inherited members.
+	pub member_count: u32,
+}
+
+/// Shared file user, group, and invitee membership. Used for the results of list_file_members
+/// and list_file_members/continue, and used as part of the results for list_file_members/batch.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedFileMembers
+{
+	/// The list of user members of the shared file.
+	pub users: Vec<UserMembershipInfo>,
+	/// The list of group members of the shared file.
+	pub groups: Vec<GroupMembershipInfo>,
+	/// The list of invited members of a file, but have not logged in and claimed this.
+	pub invitees: Vec<InviteeMembershipInfo>,
+	/// Present if there are additional shared file members that have not been returned yet.
+	/// Pass the cursor into list_file_members/continue to list additional members. This field is optional.
+	pub cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFilesArg
+{
+	/// Number of files to return max per query. Defaults to 100 if no limit is specified.
+	pub limit: u32,
+}
+
+impl Default for ListFilesArg
+{
+	fn default()
+	-> ListFilesArg
+	{
+		ListFilesArg
+		{
+			limit: 100,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFilesContinueArg
+{
+	/// Cursor in ListFilesResult.cursor.
+	pub cursor: String,
+}
+
+/// Success results for list_received_files.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListFilesResult
+{
+	/// Information about the files shared with current user.
+	pub entries: Vec<SharedFileMetadata>,
+	/// Cursor used to obtain additional shared files. This field is optional.
+	pub cursor: Option<String>,
+}
+
+/// Properties of the shared file.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedFileMetadata
+{
+	/// The ID of the file.
+	pub id: String,
+	/// The name of this file.
+	pub name: String,
+	/// Policies governing this shared file.
+	pub policy: FolderPolicy,
+	/// URL for displaying a web preview of the shared file.
+	pub preview_url: String,
+	/// The current user's access level for this shared file. This field is optional.
+	pub access_type: Option<AccessLevel>,
+	/// The display names of the users that own the file. If the file is part of a team folder,
+	/// the display names of the team admins are also included. Absent if the owner display names
+	/// cannot be fetched. This field is optional.
+	pub owner_display_names: Option<Vec<String>>,
+	/// The ID of the parent shared folder. This field is present only if the file is contained
+	/// within a shared folder. This field is optional.
+	pub parent_shared_folder_id: Option<String>,
+	/// The cased path to be used for display purposes only. This field is optional.
+	pub path_display: Option<String>,
+	/// The lower-case full path of this file. Absent for unmounted files. This field is optional.
+	pub path_lower: Option<String>,
+	/// Timestamp indicating when the current user was invited to this shared file.
+	/// If the user was not invited to the shared file, the timestamp will indicate when the user
+	/// was invited to the parent shared folder. This value may be absent. This field is optional.
+	pub time_invited: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListFoldersArgs
+{
+	/// The maximum number of results to return per request. The default for this field is 1000.
+	pub limit: u32,
+}
+
+impl Default for ListFoldersArgs
+{
+	fn default()
+	-> ListFoldersArgs
+	{
+		ListFoldersArgs
+		{
+			limit: 1000,
+		}
+	}
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ListFoldersContinueArg
+{
+	/// The cursor returned by the previous API call specified in the endpoint description.
+	pub cursor: String,
+}
+
+/// Result for list_folders or list_mountable_folders, depending on which endpoint was requested.
+/// Unmounted shared folders can be identified by the absence of SharedFolderMetadata.path_lower.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ListFoldersResult
+{
+	/// List of all shared folders the authenticated user has access to.
+	pub entries: Vec<SharedFolderMetadata>,
+	/// Present if there are additional shared folders that have not been returned yet.
+	/// Pass the cursor into the corresponding continue endpoint to list additional shared folders.
+	/// This field is optional.
+	pub cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct MountFolderArg
+{
+	/// The ID of the shared folder to mount.
+	pub shared_folder_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct InsufficientQuotaAmounts
+{
+	/// The amount of space needed to add the item (the size of the item).
+	pub space_needed: u64,
+	/// The amount of extra space needed to add the item.
+	pub space_shortage: u64,
+	/// The amount of space left in the user's Dropbox, less than space_needed.
+	pub space_left: u64,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UnmountFolderArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RelinquishFolderMembershipArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// Keep a copy of the folder's contents upon relinquishing membership.
+	/// This must be set to false when the folder is within a team folder or another shared folder.
+	/// The default for this field is False.
+	pub leave_a_copy: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct TransferFolderArg
+{
+	/// The ID for the shared folder.
+	pub shared_folder_id: String,
+	/// A account or team member ID to transfer ownership to.
+	pub to_dropbox_id: String,
+}
+
+/// How far a shared link is exposed, as classified by DropboxSharing::audit_shared_links.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum LinkExposure
+{
+	/// Anyone who has received the link can access it.
+	Public,
+	/// Only members of the same team can access the link.
+	TeamOnly,
+	/// A link-specific password is required to access the link.
+	Password,
+	/// The link has expired and can't be accessed anymore.
+	Expired,
+	/// Only members of the content or nobody can access the link.
+	Restricted,
+}
+
+impl LinkExposure
+{
+	/// Classifies a shared link at the point in time `now`, which has to be in the format
+	/// Dropbox uses for timestamps, e.g. "2015-05-12T15:50:38Z".
+	/// The effective audience decides, if Dropbox reports one. Otherwise the resolved visibility is used,
+	/// and if that is missing or unknown as well, the requested visibility.
+	/// Only a SharedLinkCreatePolicy::TeamOnly policy restricts a link to the team, every other
+	/// policy allows public links, so a link without any audience or visibility is reported as public.
+	pub fn classify(link: &SharedLinkMetadata, policy: Option<&SharedLinkCreatePolicy>, now: &str)
+	-> LinkExposure
+	{
+		if link.expires().is_some_and(|e| e <= now)
+		{
+			return LinkExposure::Expired;
+		}
+		let permissions = link.link_permissions();
+		let password = match permissions.resolved_visibility
+		{
+			Some(ResolvedVisibility::Password) |
+			Some(ResolvedVisibility::TeamAndPassword) => true,
+			_ => permissions.requested_visibility == Some(RequestedVisibility::Password),
+		};
+		match permissions.effective_audience
+		{
+			Some(LinkAudience::NoOne) |
+			Some(LinkAudience::Members) => return LinkExposure::Restricted,
+			Some(LinkAudience::Public) |
+			Some(LinkAudience::Team) if password => return LinkExposure::Password,
+			Some(LinkAudience::Public) => return LinkExposure::Public,
+			Some(LinkAudience::Team) => return LinkExposure::TeamOnly,
+			Some(LinkAudience::Other) | None => {},
+		}
+		match permissions.resolved_visibility
+		{
+			Some(ResolvedVisibility::Public) => return LinkExposure::Public,
+			Some(ResolvedVisibility::TeamOnly) => return LinkExposure::TeamOnly,
+			Some(ResolvedVisibility::Password) |
+			Some(ResolvedVisibility::TeamAndPassword) => return LinkExposure::Password,
+			Some(ResolvedVisibility::SharedFolderOnly) |
+			Some(ResolvedVisibility::NoOne) |
+			Some(ResolvedVisibility::OnlyYou) => return LinkExposure::Restricted,
+			Some(ResolvedVisibility::Other) | None => {},
+		}
+		let team_only = policy == Some(&SharedLinkCreatePolicy::TeamOnly);
+		match permissions.requested_visibility
+		{
+			Some(RequestedVisibility::Password) => LinkExposure::Password,
+			Some(RequestedVisibility::TeamOnly) => LinkExposure::TeamOnly,
+			Some(RequestedVisibility::Public) | None if team_only => LinkExposure::TeamOnly,
+			Some(RequestedVisibility::Public) | None => LinkExposure::Public,
+		}
+	}
+}
+
+/// Report of DropboxSharing::audit_shared_links.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedLinkAuditReport
+{
+	/// The time the report was generated at.
+	pub generated_at: String,
+	/// The shared link policy of the account's team, if the account is a member of a team.
+	pub policy: Option<SharedLinkCreatePolicy>,
+	/// Every shared link of the account.
+	pub entries: Vec<SharedLinkAuditEntry>,
+}
+
+impl SharedLinkAuditReport
+{
+	/// The links which can be accessed by anyone.
+	pub fn public_links(&self)
+	-> Vec<&SharedLinkAuditEntry>
+	{
+		self.entries.iter()
+			.filter(|e| e.exposure == LinkExposure::Public)
+			.collect()
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedLinkAuditEntry
+{
+	/// URL of the shared link.
+	pub url: String,
+	/// The linked file or folder name.
+	pub name: String,
+	/// The exposure of the link.
+	pub exposure: LinkExposure,
+	/// Expiration time, if set.
+	pub expires: Option<String>,
+	/// The cased path of the linked file or folder, if it is in the user's Dropbox.
+	pub path_display: Option<String>,
+	/// The size of the linked file in bytes. Not present for folders.
+	pub size: Option<u64>,
+	/// The last time the linked file was modified on Dropbox. Not present for folders.
+	pub server_modified: Option<String>,
+}
+
+/// Report of DropboxSharing::access_report.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AccessReport
+{
+	/// The root of the folder tree the report was generated for.
+	pub path: String,
+	/// One row for every member of every shared folder and shared file below the path.
+	pub rows: Vec<AccessReportRow>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AccessReportRow
+{
+	/// The cased path of the shared folder or file.
+	pub path: String,
+	/// The member who has access.
+	pub member: AccessMember,
+	/// The access level of the member.
+	pub access_level: AccessLevel,
+	/// True if the member has access from a parent folder.
+	pub is_inherited: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AccessMember
+{
+	/// A Dropbox user.
+	User{ account_id: String, name: String, email: String },
+	/// A group.
+	Group{ group_id: String, group_name: String },
+	/// A user who has been invited but hasn't accepted yet.
+	Invitee{ email: String },
+}
@@ -1,3 +1,5 @@
+use ::models::common::RootInfo;
+
 #[derive(Serialize, Debug, Clone, Default, PartialEq)]
 pub struct GetAccountArg
 {
@@ -86,6 +88,9 @@ pub struct FullAccount
 	pub team: Option<FullTeam>,
 	/// This account's unique team member id. This field will only be present if team is present.
 	pub team_member_id: Option<String>,
+	/// The root info for this account. Use root_namespace_id with PathRoot::Root
+	/// to access the team space of accounts on a team with a separate team root.
+	pub root_info: RootInfo,
 }
 
 /// What type of account this user has.
@@ -131,7 +136,8 @@ pub struct TeamSharingPolicies
 
 /// Policy governing who can be a member of a folder shared by a team member.
 /// This datatype comes from an imported namespace originally defined in the team_policies namespace.
-#[derive( Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
 pub enum SharedFolderMemberPolicy
 {
 	/// Only a teammate can be a member of a folder shared by a team member.
@@ -144,7 +150,8 @@ pub enum SharedFolderMemberPolicy
 
 /// Policy governing which shared folders a team member can join.
 /// This datatype comes from an imported namespace originally defined in the team_policies namespace.
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
 pub enum SharedFolderJoinPolicy
 {
 	/// Team members can only join folders shared by teammates.
@@ -158,7 +165,8 @@ pub enum SharedFolderJoinPolicy
 /// Policy governing the visibility of shared links. This policy can apply to newly created shared links,
 /// or all shared links.
 /// This datatype comes from an imported namespace originally defined in the team_policies namespace.
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag=".tag")]
 pub enum SharedLinkCreatePolicy
 {
 	/// By default, anyone can access newly created shared links.
@@ -0,0 +1,239 @@
+use std::io::Read;
+
+use base64;
+use hyper::Client;
+use hyper::header::*;
+use rand::{self, Rng};
+use serde_json;
+use serde_urlencoded;
+use sha2::{Digest, Sha256};
+
+use ::error::*;
+use ::models::auth::*;
+use ::models::error::*;
+use ::secret::Secret;
+use ::BASE_URL;
+use ::USER_AGENT;
+
+static AUTHORIZE_URL: &str = "https://www.dropbox.com/oauth2/authorize";
+
+/// OAuth2 authorization code flow.
+///
+/// Direct the user to `authorize_url()`. After the user approved the app,
+/// Dropbox redirects to the redirect uri with a `code` and a `state` query parameter,
+/// which are passed to `finish()` to get the access token.
+///
+/// Apps which can't keep their app secret confidential, like desktop or mobile apps,
+/// should use `new_pkce()` instead of `new()`.
+pub struct OAuth2Flow
+{
+	client_id: String,
+	client_secret: Option<Secret>,
+	redirect_uri: String,
+	state: String,
+	code_verifier: Option<Secret>,
+	token_access_type: Option<TokenAccessType>,
+	scopes: Vec<String>,
+}
+
+impl OAuth2Flow
+{
+	/// Creates a new flow with a randomly generated state.
+	pub fn new(client_id: String, client_secret: String, redirect_uri: String)
+	-> OAuth2Flow
+	{
+		OAuth2Flow
+		{
+			client_id: client_id,
+			client_secret: Some(Secret::new(client_secret)),
+			redirect_uri: redirect_uri,
+			state: generate_state(),
+			code_verifier: None,
+			token_access_type: None,
+			scopes: vec![],
+		}
+	}
+
+	/// Creates a new flow using PKCE (Proof Key for Code Exchange) with a randomly generated
+	/// state and code verifier. The code is exchanged without the app secret.
+	pub fn new_pkce(client_id: String, redirect_uri: String)
+	-> OAuth2Flow
+	{
+		OAuth2Flow
+		{
+			client_id: client_id,
+			client_secret: None,
+			redirect_uri: redirect_uri,
+			state: generate_state(),
+			code_verifier: Some(Secret::new(generate_code_verifier())),
+			token_access_type: None,
+			scopes: vec![],
+		}
+	}
+
+	/// Sets the type of token that should be issued.
+	/// Use TokenAccessType::Offline to also receive a refresh token.
+	pub fn token_access_type(mut self, token_access_type: TokenAccessType)
+	-> OAuth2Flow
+	{
+		self.token_access_type = Some(token_access_type);
+		self
+	}
+
+	/// Sets the scopes to request. If none are set, all scopes the app has are requested.
+	pub fn scopes(mut self, scopes: Vec<String>)
+	-> OAuth2Flow
+	{
+		self.scopes = scopes;
+		self
+	}
+
+	/// The state which is sent to Dropbox and has to be returned to the redirect uri.
+	/// Store it together with the flow if the flow has to be recreated later.
+	pub fn state(&self)
+	-> &str
+	{
+		&self.state
+	}
+
+	/// Replaces the generated state, e.g. with one restored from the user's session.
+	pub fn set_state(&mut self, state: String)
+	{
+		self.state = state;
+	}
+
+	/// The code verifier of a PKCE flow. Store it together with the state
+	/// if the flow has to be recreated later.
+	pub fn code_verifier(&self)
+	-> Option<&str>
+	{
+		self.code_verifier.as_ref().map(|v| v.expose())
+	}
+
+	/// Replaces the generated code verifier of a PKCE flow.
+	pub fn set_code_verifier(&mut self, code_verifier: String)
+	{
+		self.code_verifier = Some(Secret::new(code_verifier));
+	}
+
+	/// The url the user has to be directed to, to authorize the app.
+	pub fn authorize_url(&self)
+	-> Result<String>
+	{
+		authorize_url(&self.client_id, &self.redirect_uri, &self.state,
+			&self.token_access_type, &self.scopes, self.code_verifier())
+	}
+
+	/// Validates the returned state and exchanges the code for an access token.
+	pub fn finish(&self, code: &str, state: &str)
+	-> Result<TokenResponse>
+	{
+		if state != self.state
+		{
+			return Err(DropboxError::StateMismatch);
+		}
+		let token = Token
+		{
+			code: Secret::from(code),
+			grant_type: "authorization_code".to_owned(),
+			client_id: self.client_id.clone(),
+			client_secret: self.client_secret.clone(),
+			redirect_uri: self.redirect_uri.clone(),
+			code_verifier: self.code_verifier.clone(),
+		};
+		let body = serde_urlencoded::to_string(&token)?;
+		request_token(&::create_client()?, BASE_URL, USER_AGENT, &body)
+	}
+
+	/// Creates the credential for a token returned by `finish()`.
+	/// It contains the app key and secret of this flow, so that Dropbox can refresh short-lived access tokens.
+	pub fn credential(&self, token: TokenResponse)
+	-> Credential
+	{
+		Credential
+		{
+			account_id: token.account_id,
+			access_token: token.access_token,
+			expires_at: token.expires_in.map(|e| ::now() + e),
+			refresh_token: token.refresh_token,
+			app_key: Some(self.client_id.clone()),
+			app_secret: self.client_secret.clone(),
+		}
+	}
+}
+
+fn authorize_url(client_id: &str, redirect_uri: &str, state: &str,
+	token_access_type: &Option<TokenAccessType>, scopes: &[String], code_verifier: Option<&str>)
+-> Result<String>
+{
+	let mut params = vec![
+		("client_id", client_id),
+		("response_type", "code"),
+		("redirect_uri", redirect_uri),
+		("state", state),
+	];
+	match *token_access_type
+	{
+		Some(TokenAccessType::Online) => params.push(("token_access_type", "online")),
+		Some(TokenAccessType::Offline) => params.push(("token_access_type", "offline")),
+		Some(TokenAccessType::Legacy) => params.push(("token_access_type", "legacy")),
+		None => {},
+	}
+	let scope = scopes.join(" ");
+	if !scope.is_empty()
+	{
+		params.push(("scope", &scope));
+	}
+	let code_challenge = code_verifier.map(code_challenge);
+	if let Some(ref code_challenge) = code_challenge
+	{
+		params.push(("code_challenge", code_challenge));
+		params.push(("code_challenge_method", "S256"));
+	}
+	Ok(format!("{}?{}", AUTHORIZE_URL, serde_urlencoded::to_string(&params)?))
+}
+
+/// Sends a form encoded request to the /oauth2/token route of the api host.
+pub(crate) fn request_token(client: &Client, host: &str, user_agent: &str, body: &str)
+-> Result<TokenResponse>
+{
+	let uri = format!("{}/oauth2/token", host);
+	let mut header = Headers::new();
+	header.set(UserAgent(user_agent.to_owned()));
+	header.set(ContentType::form_url_encoded());
+	let mut resp = client.post(&uri)
+		.headers(header)
+		.body(body)
+		.send()?;
+	let mut body = String::new();
+	resp.read_to_string(&mut body)?;
+	match serde_json::from_str::<TokenResponse>(&body)
+	{
+		Err(_) => Err(match serde_json::from_str::<OAuth2Error>(&body)
+		{
+			Err(_) => DropboxError::Other,
+			Ok(r) => DropboxError::OAuth2Error(r),
+		}),
+		Ok(r) => Ok(r),
+	}
+}
+
+fn generate_state()
+-> String
+{
+	rand::thread_rng().gen_ascii_chars().take(32).collect()
+}
+
+/// Generates a code verifier of 64 characters, which is within the 43 to 128 characters required by PKCE.
+fn generate_code_verifier()
+-> String
+{
+	rand::thread_rng().gen_ascii_chars().take(64).collect()
+}
+
+/// Derives the S256 code challenge, the base64url encoded SHA-256 hash of the code verifier.
+pub(crate) fn code_challenge(code_verifier: &str)
+-> String
+{
+	base64::encode_config(&Sha256::digest(code_verifier.as_bytes()), base64::URL_SAFE_NO_PAD)
+}
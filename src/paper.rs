@@ -0,0 +1,407 @@
+use std::io::Write;
+use std::path::Path;
+
+use serde_json;
+
+use ::cursor::CursorIter;
+use ::error::*;
+use ::Dropbox;
+use ::models::paper::*;
+use ::models::sharing::{InviteeInfo, UserInfo};
+use ::models::error::*;
+
+pub struct DropboxPaper<'a>
+{
+	dropbox: &'a Dropbox,
+}
+
+impl<'a> DropboxPaper<'a>
+{
+	pub fn new(dropbox: &'a Dropbox)
+	-> DropboxPaper<'a>
+	{
+		DropboxPaper
+		{
+			dropbox: dropbox,
+		}
+	}
+
+	/// Return the list of all Paper docs according to the argument specifications.
+	/// To iterate over through the full pagination, pass the cursor to docs_list_continue.
+	pub fn docs_list(&self, arg: ListPaperDocsArgs)
+	-> Result<ListPaperDocsResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "list");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListPaperDocsResponse>(&resp)
+		{
+			Err(_) => Err(DropboxError::Other),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from docs_list, use this to paginate through all Paper doc.
+	pub fn docs_list_continue(&self, arg: ListPaperDocsContinueArgs)
+	-> Result<ListPaperDocsResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "list", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListPaperDocsResponse>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListDocsCursorError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListDocsCursorError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over the ids of every Paper doc returned by docs_list and docs_list_continue.
+	pub fn docs_list_iter(&self, arg: ListPaperDocsArgs)
+	-> Result<CursorIter<'a, String>>
+	{
+		let first = self.docs_list(arg)?;
+		let cursor = if first.has_more { Some(first.cursor.value) } else { None };
+		let paper = DropboxPaper::new(self.dropbox);
+		Ok(CursorIter::new(first.doc_ids, cursor, move |cursor|
+		{
+			let page = paper.docs_list_continue(ListPaperDocsContinueArgs
+			{
+				cursor: cursor,
+			})?;
+			let cursor = if page.has_more { Some(page.cursor.value) } else { None };
+			Ok((page.doc_ids, cursor))
+		}))
+	}
+
+	/// Exports and downloads Paper doc either as HTML or markdown into the writer.
+	pub fn docs_download<W: Write>(&self, arg: PaperDocExport, writer: &mut W)
+	-> Result<PaperDocExportResult>
+	{
+		// unlike files/download, the Paper content routes are served by the api host
+		let uri = gen_uri!("paper", "docs", "download");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.download_to(&uri, &body, writer)?;
+		match serde_json::from_str::<PaperDocExportResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Creates a new Paper doc with the provided content.
+	pub fn docs_create(&self, arg: PaperDocCreateArgs, file_path: &Path)
+	-> Result<PaperDocCreateUpdateResult>
+	{
+		let uri = gen_uri!("paper", "docs", "create");
+		let arg: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.upload(&uri, &arg, file_path)?;
+		match serde_json::from_str::<PaperDocCreateUpdateResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PaperDocCreateError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PaperDocCreateError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Updates an existing Paper doc with the provided content.
+	pub fn docs_update(&self, arg: PaperDocUpdateArgs, file_path: &Path)
+	-> Result<PaperDocCreateUpdateResult>
+	{
+		let uri = gen_uri!("paper", "docs", "update");
+		let arg: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.upload(&uri, &arg, file_path)?;
+		match serde_json::from_str::<PaperDocCreateUpdateResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PaperDocUpdateError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PaperDocUpdateError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Marks the given Paper doc as archived. This action can be performed or undone by anyone with
+	/// edit permissions to the doc. Note: this action is not reversible via the API.
+	pub fn docs_archive(&self, arg: RefPaperDoc)
+	-> Result<()>
+	{
+		let uri = gen_uri!("paper", "docs", "archive");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<DocLookupError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::DocLookupError(r)),
+		}
+	}
+
+	/// Permanently deletes the given Paper doc. This operation is final as the doc cannot be recovered.
+	/// Note: This action can be performed only by the original owner of the Paper doc.
+	pub fn docs_permanently_delete(&self, arg: RefPaperDoc)
+	-> Result<()>
+	{
+		let uri = gen_uri!("paper", "docs", "permanently_delete");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<DocLookupError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::DocLookupError(r)),
+		}
+	}
+
+	/// Retrieves folder information for the given Paper doc. This includes the folder sharing policy,
+	/// which is set by the top-level folder, and the list of folders from the root folder to the folder
+	/// directly containing the Paper doc.
+	/// Note: If the Paper doc is not in any folder (aka unfiled) the response will be empty.
+	pub fn docs_get_folder_info(&self, arg: RefPaperDoc)
+	-> Result<FoldersContainingPaperDoc>
+	{
+		let uri = gen_uri!("paper", "docs", "get_folder_info");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<FoldersContainingPaperDoc>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Allows an owner or editor to add users to a Paper doc or change their permissions
+	/// using their email address or Dropbox account ID. Note: The Doc owner's permissions cannot be changed.
+	pub fn docs_users_add(&self, arg: AddPaperDocUser)
+	-> Result<Vec<AddPaperDocUserMemberResult>>
+	{
+		let uri = gen_uri!("paper", "docs", "users", "add");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Vec<AddPaperDocUserMemberResult>>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Lists all users who visited the Paper doc or users with explicit access. This call excludes users
+	/// who have been removed. The list is sorted by the date of the visit or the share date.
+	/// The list will include both users, the explicitly shared ones as well as those who came in using the
+	/// Paper url link.
+	pub fn docs_users_list(&self, arg: ListUsersOnPaperDocArgs)
+	-> Result<ListUsersOnPaperDocResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "users", "list");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListUsersOnPaperDocResponse>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from docs_users_list, use this to paginate through all users on the Paper doc.
+	pub fn docs_users_list_continue(&self, arg: ListUsersOnPaperDocContinueArgs)
+	-> Result<ListUsersOnPaperDocResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "users", "list", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListUsersOnPaperDocResponse>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListUsersCursorError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListUsersCursorError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over every user and invitee of a Paper doc returned by docs_users_list
+	/// and docs_users_list_continue.
+	pub fn docs_users_list_iter(&self, arg: ListUsersOnPaperDocArgs)
+	-> Result<CursorIter<'a, PaperDocMember>>
+	{
+		let doc_id = arg.doc_id.clone();
+		let first = self.docs_users_list(arg)?;
+		let cursor = if first.has_more { Some(first.cursor.value) } else { None };
+		let paper = DropboxPaper::new(self.dropbox);
+		Ok(CursorIter::new(doc_members(first.users, first.invitees), cursor, move |cursor|
+		{
+			let page = paper.docs_users_list_continue(ListUsersOnPaperDocContinueArgs
+			{
+				doc_id: doc_id.clone(),
+				cursor: cursor,
+			})?;
+			let cursor = if page.has_more { Some(page.cursor.value) } else { None };
+			Ok((doc_members(page.users, page.invitees), cursor))
+		}))
+	}
+
+	/// Allows an owner or editor to remove users from a Paper doc using their email address or Dropbox account ID.
+	/// Note: Doc owner cannot be removed.
+	pub fn docs_users_remove(&self, arg: RemovePaperDocUser)
+	-> Result<()>
+	{
+		let uri = gen_uri!("paper", "docs", "users", "remove");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<DocLookupError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::DocLookupError(r)),
+		}
+	}
+
+	/// Gets the default sharing policy for the given Paper doc.
+	pub fn docs_sharing_policy_get(&self, arg: RefPaperDoc)
+	-> Result<SharingPolicy>
+	{
+		let uri = gen_uri!("paper", "docs", "sharing_policy", "get");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharingPolicy>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Sets the default sharing policy for the given Paper doc. The default 'team_sharing_policy' can be
+	/// changed only by teams, omit this field for personal accounts.
+	/// Note: 'public_sharing_policy' cannot be set to the value 'disabled' because this setting can be
+	/// changed only via the team admin console.
+	pub fn docs_sharing_policy_set(&self, arg: PaperDocSharingPolicy)
+	-> Result<()>
+	{
+		let uri = gen_uri!("paper", "docs", "sharing_policy", "set");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<DocLookupError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::DocLookupError(r)),
+		}
+	}
+
+	/// Lists the users who are explicitly invited to the Paper folder in which the Paper doc is contained.
+	/// For private folders all users (including owner) shared on the folder are listed and for team folders
+	/// all non-team users shared on the folder are returned.
+	pub fn docs_folder_users_list(&self, arg: ListUsersOnFolderArgs)
+	-> Result<ListUsersOnFolderResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "folder_users", "list");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListUsersOnFolderResponse>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<DocLookupError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::DocLookupError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from docs_folder_users_list, use this to paginate through all users on the Paper folder.
+	pub fn docs_folder_users_list_continue(&self, arg: ListUsersOnFolderContinueArgs)
+	-> Result<ListUsersOnFolderResponse>
+	{
+		let uri = gen_uri!("paper", "docs", "folder_users", "list", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListUsersOnFolderResponse>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListUsersCursorError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListUsersCursorError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over every user and invitee of the folder containing a Paper doc returned by
+	/// docs_folder_users_list and docs_folder_users_list_continue.
+	pub fn docs_folder_users_list_iter(&self, arg: ListUsersOnFolderArgs)
+	-> Result<CursorIter<'a, PaperFolderMember>>
+	{
+		let doc_id = arg.doc_id.clone();
+		let first = self.docs_folder_users_list(arg)?;
+		let cursor = if first.has_more { Some(first.cursor.value) } else { None };
+		let paper = DropboxPaper::new(self.dropbox);
+		Ok(CursorIter::new(folder_members(first.users, first.invitees), cursor, move |cursor|
+		{
+			let page = paper.docs_folder_users_list_continue(ListUsersOnFolderContinueArgs
+			{
+				doc_id: doc_id.clone(),
+				cursor: cursor,
+			})?;
+			let cursor = if page.has_more { Some(page.cursor.value) } else { None };
+			Ok((folder_members(page.users, page.invitees), cursor))
+		}))
+	}
+}
+
+fn doc_members(users: Vec<UserInfoWithPermissionLevel>, invitees: Vec<InviteeInfoWithPermissionLevel>)
+-> Vec<PaperDocMember>
+{
+	users.into_iter()
+		.map(PaperDocMember::User)
+		.chain(invitees.into_iter().map(PaperDocMember::Invitee))
+		.collect()
+}
+
+fn folder_members(users: Vec<UserInfo>, invitees: Vec<InviteeInfo>)
+-> Vec<PaperFolderMember>
+{
+	users.into_iter()
+		.map(PaperFolderMember::User)
+		.chain(invitees.into_iter().map(PaperFolderMember::Invitee))
+		.collect()
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::ptr;
+
+use hyper::header::{Authorization, Headers};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Value};
+
+/// Keys of json bodies whose values are redacted before they are logged.
+static SECRET_KEYS: &[&str] = &["access_token", "refresh_token", "oauth1_token", "oauth1_token_secret",
+	"oauth2_token", "code", "code_verifier", "client_secret", "link_password", "password"];
+
+/// A string holding a token, code or secret.
+///
+/// Its Debug output is redacted and its memory is zeroed when it is dropped.
+/// The latter doesn't cover the copy hyper needs for the Authorization header,
+/// as its Bearer and Basic schemes only take an owned String.
+/// Serialization writes the plain value, as it is needed in request bodies and credential stores.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret
+{
+	pub fn new(secret: String)
+	-> Secret
+	{
+		Secret(secret)
+	}
+
+	/// Returns the plain value.
+	pub fn expose(&self)
+	-> &str
+	{
+		&self.0
+	}
+}
+
+impl fmt::Debug for Secret
+{
+	fn fmt(&self, f: &mut fmt::Formatter)
+	-> fmt::Result
+	{
+		f.write_str("Secret(***)")
+	}
+}
+
+impl Drop for Secret
+{
+	fn drop(&mut self)
+	{
+		// volatile writes, so that the zeroing isn't optimized away
+		for byte in unsafe { self.0.as_mut_vec() }.iter_mut()
+		{
+			unsafe { ptr::write_volatile(byte, 0) };
+		}
+	}
+}
+
+impl From<String> for Secret
+{
+	fn from(secret: String)
+	-> Secret
+	{
+		Secret(secret)
+	}
+}
+
+impl<'a> From<&'a str> for Secret
+{
+	fn from(secret: &'a str)
+	-> Secret
+	{
+		Secret(secret.to_owned())
+	}
+}
+
+impl Serialize for Secret
+{
+	fn serialize<S>(&self, serializer: S)
+	-> ::std::result::Result<S::Ok, S::Error>
+		where S: Serializer
+	{
+		serializer.serialize_str(&self.0)
+	}
+}
+
+impl<'de> Deserialize<'de> for Secret
+{
+	fn deserialize<D>(deserializer: D)
+	-> ::std::result::Result<Secret, D::Error>
+		where D: Deserializer<'de>
+	{
+		String::deserialize(deserializer).map(Secret)
+	}
+}
+
+/// Formats headers for logging, without the value of the Authorization header.
+/// The json argument in the Dropbox-API-Arg header is redacted like a body, as it can hold a link password.
+pub(crate) fn redact_headers(headers: &Headers)
+-> String
+{
+	headers.iter()
+		.map(|h| if h.is::<Authorization<::hyper::header::Bearer>>()
+			|| h.is::<Authorization<::hyper::header::Basic>>()
+		{
+			format!("{}: ***", h.name())
+		}
+		else if h.name().eq_ignore_ascii_case("Dropbox-API-Arg")
+		{
+			format!("{}: {}", h.name(), redact_body(&h.value_string()))
+		}
+		else
+		{
+			format!("{}: {}", h.name(), h.value_string())
+		})
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+/// Formats a json body for logging, with the values of token, code, secret and password fields replaced.
+/// Bodies which aren't json are returned unchanged.
+pub(crate) fn redact_body(body: &str)
+-> String
+{
+	match serde_json::from_str::<Value>(body)
+	{
+		Err(_) => body.to_owned(),
+		Ok(mut value) =>
+		{
+			redact_value(&mut value);
+			value.to_string()
+		},
+	}
+}
+
+fn redact_value(value: &mut Value)
+{
+	match *value
+	{
+		Value::Object(ref mut map) => for (key, value) in map.iter_mut()
+		{
+			if SECRET_KEYS.contains(&key.as_str()) && value.is_string()
+			{
+				*value = Value::String("***".to_owned());
+			}
+			else
+			{
+				redact_value(value);
+			}
+		},
+		Value::Array(ref mut values) => for value in values.iter_mut()
+		{
+			redact_value(value);
+		},
+		_ => {},
+	}
+}
@@ -0,0 +1,946 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+use serde_json;
+
+use ::cursor::CursorIter;
+use ::error::*;
+use ::Dropbox;
+use ::files::DropboxFiles;
+use ::users::DropboxUsers;
+use ::models::files::{GetMetadataArg, ListFolderArg, Metadata, PollArg};
+use ::models::users::{BasicAccount, GetAccountBatchArg};
+use ::models::sharing::*;
+use ::models::error::*;
+
+pub struct DropboxSharing<'a>
+{
+	dropbox: &'a Dropbox,
+}
+
+impl<'a> DropboxSharing<'a>
+{
+	pub fn new(dropbox: &'a Dropbox)
+	-> DropboxSharing<'a>
+	{
+		DropboxSharing
+		{
+			dropbox: dropbox,
+		}
+	}
+
+	/// Create a shared link with custom settings. If no settings are given then the default
+	/// visibility is RequestedVisibility.public (The resolved visibility, though,
+	/// may depend on other aspects such as team and shared folder settings).
+	pub fn create_shared_link_with_settings(&self, arg: CreateSharedLinkWithSettingsArg)
+	-> Result<SharedLinkMetadata>
+	{
+		let uri = gen_uri!("sharing", "create_shared_link_with_settings");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedLinkMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<CreateSharedLinkWithSettingsError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::CreateSharedLinkWithSettingsError(Box::new(r)),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// List shared links of this user. If no path is given, returns a list of all shared links
+	/// for the current user. If a non-empty path is given, returns a list of all shared links
+	/// that allow access to the given path - direct links to the given path and links to parent
+	/// folders of the given path. Links to parent folders can be suppressed by setting direct_only to true.
+	pub fn list_shared_links(&self, arg: ListSharedLinksArg)
+	-> Result<ListSharedLinksResult>
+	{
+		let uri = gen_uri!("sharing", "list_shared_links");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListSharedLinksResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListSharedLinksError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListSharedLinksError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Iterates over all shared links, fetching the next page with the cursor whenever has_more is set.
+	pub fn list_shared_links_iter(&self, arg: ListSharedLinksArg)
+	-> Result<CursorIter<'a, SharedLinkMetadata>>
+	{
+		let first = self.list_shared_links(arg.clone())?;
+		let cursor = if first.has_more { first.cursor } else { None };
+		let sharing = DropboxSharing::new(self.dropbox);
+		Ok(CursorIter::new(first.links, cursor, move |cursor|
+		{
+			let page = sharing.list_shared_links(ListSharedLinksArg
+			{
+				cursor: Some(cursor),
+				..arg.clone()
+			})?;
+			let cursor = if page.has_more { page.cursor } else { None };
+			Ok((page.links, cursor))
+		}))
+	}
+
+	/// Lists every shared link of the current user, classifies how far each link is exposed and joins
+	/// it with the metadata of the linked file or folder. Links whose content is not in the user's
+	/// Dropbox anymore are reported without metadata.
+	/// For team members the team's SharedLinkCreatePolicy is used for links without a resolved visibility.
+	pub fn audit_shared_links(&self)
+	-> Result<SharedLinkAuditReport>
+	{
+		let account = DropboxUsers::new(self.dropbox).get_current_account()?;
+		let policy = account.team.map(|t| t.sharing_policies.shared_link_create_policy);
+		let now = ::format_timestamp(::now());
+		let files = DropboxFiles::new(self.dropbox);
+		let mut entries = vec![];
+		for link in self.list_shared_links_iter(ListSharedLinksArg::default())?
+		{
+			let link = link?;
+			let metadata = match link.path_lower()
+			{
+				None => None,
+				Some(path) => match files.get_metadata(GetMetadataArg
+				{
+					path: path.to_owned(),
+					include_media_info: false,
+					include_deleted: false,
+					include_has_explicit_shared_members: false,
+				})
+				{
+					Err(DropboxError::GetMetadataError(_)) => None,
+					Err(e) => return Err(e),
+					Ok(r) => Some(r),
+				},
+			};
+			let (path_display, size, server_modified) = match metadata
+			{
+				Some(Metadata::File(r)) => (r.path_display, Some(r.size), Some(r.server_modified)),
+				Some(Metadata::Folder(r)) => (r.path_display, None, None),
+				Some(Metadata::Deleted(r)) => (r.path_display, None, None),
+				None => (None, None, None),
+			};
+			entries.push(SharedLinkAuditEntry
+			{
+				url: link.url().to_owned(),
+				name: match link
+				{
+					SharedLinkMetadata::File(ref r) => r.name.clone(),
+					SharedLinkMetadata::Folder(ref r) => r.name.clone(),
+				},
+				exposure: LinkExposure::classify(&link, policy.as_ref(), &now),
+				expires: link.expires().map(|e| e.to_owned()),
+				path_display: path_display,
+				size: size,
+				server_modified: server_modified,
+			});
+		}
+		Ok(SharedLinkAuditReport
+		{
+			generated_at: now,
+			policy: policy,
+			entries: entries,
+		})
+	}
+
+	/// Modify the shared link's settings. If the requested visibility conflict with the shared links
+	/// policy of the team or the shared folder (in case the linked file is part of a shared folder)
+	/// then the LinkPermissions.resolved_visibility of the returned SharedLinkMetadata will reflect
+	/// the actual visibility of the shared link and the LinkPermissions.requested_visibility will
+	/// reflect the requested visibility.
+	pub fn modify_shared_link_settings(&self, arg: ModifySharedLinkSettingsArgs)
+	-> Result<SharedLinkMetadata>
+	{
+		let uri = gen_uri!("sharing", "modify_shared_link_settings");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedLinkMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ModifySharedLinkSettingsError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ModifySharedLinkSettingsError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Revoke a shared link. Note that even after revoking a shared link to a file,
+	/// the file may be accessible if there are shared links leading to any of the file parent folders.
+	/// To list all shared links that enable access to a specific file, you can use
+	/// list_shared_links with the file as the path argument.
+	pub fn revoke_shared_link(&self, arg: RevokeSharedLinkArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("sharing", "revoke_shared_link");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<RevokeSharedLinkError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::RevokeSharedLinkError(r)),
+		}
+	}
+
+	/// Get the shared link's metadata. If the shared link is to a folder,
+	/// path can be used to get the metadata of a file or sub-folder in it.
+	pub fn get_shared_link_metadata(&self, url: &str, path: Option<&str>, password: Option<&str>)
+	-> Result<SharedLinkMetadata>
+	{
+		let uri = gen_uri!("sharing", "get_shared_link_metadata");
+		let arg = GetSharedLinkMetadataArg
+		{
+			url: url.to_owned(),
+			path: path.map(|p| p.to_owned()),
+			link_password: password.map(|p| p.into()),
+		};
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedLinkMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<SharedLinkError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::SharedLinkError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Download the shared link's file from a user's Dropbox into the writer.
+	/// If the shared link is to a folder, path selects the file in it. The content is streamed,
+	/// so large files don't have to fit into memory.
+	pub fn get_shared_link_file<W: Write>(&self, url: &str, path: Option<&str>, password: Option<&str>, writer: &mut W)
+	-> Result<SharedLinkMetadata>
+	{
+		let uri = gen_upload_uri!("sharing", "get_shared_link_file");
+		let arg = GetSharedLinkMetadataArg
+		{
+			url: url.to_owned(),
+			path: path.map(|p| p.to_owned()),
+			link_password: password.map(|p| p.into()),
+		};
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.download_to(&uri, &body, writer)?;
+		match serde_json::from_str::<SharedLinkMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<GetSharedLinkFileError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::GetSharedLinkFileError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Share a folder with collaborators. Most sharing will be completed synchronously.
+	/// Large folders will be completed asynchronously. To make testing the async case repeatable,
+	/// set ShareFolderArg.force_async. If a ShareFolderLaunch.async_job_id is returned,
+	/// you'll need to call check_share_job_status until the action completes to get the metadata for the folder.
+	pub fn share_folder(&self, arg: ShareFolderArg)
+	-> Result<ShareFolderLaunch>
+	{
+		let uri = gen_uri!("sharing", "share_folder");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ShareFolderLaunch>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ShareFolderError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ShareFolderError(Box::new(r)),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns the current status of an asynchronous job for sharing a folder.
+	pub fn check_share_job_status(&self, arg: PollArg)
+	-> Result<ShareFolderJobStatus>
+	{
+		let uri = gen_uri!("sharing", "check_share_job_status");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ShareFolderJobStatus>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PollError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PollError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns the current status of an asynchronous job, e.g. of unshare_folder.
+	pub fn check_job_status(&self, arg: PollArg)
+	-> Result<JobStatus>
+	{
+		let uri = gen_uri!("sharing", "check_job_status");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<JobStatus>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PollError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PollError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Allows a shared folder owner to unshare the folder. You'll need to call check_job_status
+	/// to determine if the action has completed successfully.
+	pub fn unshare_folder(&self, arg: UnshareFolderArg)
+	-> Result<LaunchEmptyResult>
+	{
+		let uri = gen_uri!("sharing", "unshare_folder");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<LaunchEmptyResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<UnshareFolderError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::UnshareFolderError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Allows an owner or editor (if the ACL update policy allows) of a shared folder to add another member.
+	/// For the new member to get access to all the functionality for this folder, you will need to call
+	/// mount_folder on their behalf.
+	pub fn add_folder_member(&self, arg: AddFolderMemberArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("sharing", "add_folder_member");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<AddFolderMemberError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::AddFolderMemberError(r)),
+		}
+	}
+
+	/// Allows an owner or editor (if the ACL update policy allows) of a shared folder to remove another member.
+	/// You'll need to call check_remove_member_job_status to determine if the action has completed.
+	pub fn remove_folder_member(&self, arg: RemoveFolderMemberArg)
+	-> Result<LaunchResultBase>
+	{
+		let uri = gen_uri!("sharing", "remove_folder_member");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<LaunchResultBase>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<RemoveFolderMemberError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::RemoveFolderMemberError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns the current status of an asynchronous job for remove_folder_member.
+	pub fn check_remove_member_job_status(&self, arg: PollArg)
+	-> Result<RemoveMemberJobStatus>
+	{
+		let uri = gen_uri!("sharing", "check_remove_member_job_status");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<RemoveMemberJobStatus>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<PollError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::PollError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Allows an owner or editor of a shared folder to update another member's permissions.
+	pub fn update_folder_member(&self, arg: UpdateFolderMemberArg)
+	-> Result<MemberAccessLevelResult>
+	{
+		let uri = gen_uri!("sharing", "update_folder_member");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<MemberAccessLevelResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<UpdateFolderMemberError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::UpdateFolderMemberError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns shared folder membership by its folder ID.
+	pub fn list_folder_members(&self, arg: ListFolderMembersArgs)
+	-> Result<SharedFolderMembers>
+	{
+		let uri = gen_uri!("sharing", "list_folder_members");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFolderMembers>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<SharedFolderAccessError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::SharedFolderAccessError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from list_folder_members, use this to paginate through all shared folder members.
+	pub fn list_folder_members_continue(&self, arg: ListFolderMembersContinueArg)
+	-> Result<SharedFolderMembers>
+	{
+		let uri = gen_uri!("sharing", "list_folder_members", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFolderMembers>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFolderMembersContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFolderMembersContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns shared folder metadata by its folder ID.
+	pub fn get_folder_metadata(&self, arg: GetMetadataArgs)
+	-> Result<SharedFolderMetadata>
+	{
+		let uri = gen_uri!("sharing", "get_folder_metadata");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFolderMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<SharedFolderAccessError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::SharedFolderAccessError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Adds specified members to a file.
+	pub fn add_file_member(&self, arg: AddFileMemberArgs)
+	-> Result<Vec<FileMemberActionResult>>
+	{
+		let uri = gen_uri!("sharing", "add_file_member");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Vec<FileMemberActionResult>>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<AddFileMemberError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::AddFileMemberError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Removes a specified member from the file.
+	pub fn remove_file_member_2(&self, arg: RemoveFileMemberArg)
+	-> Result<FileMemberRemoveActionResult>
+	{
+		let uri = gen_uri!("sharing", "remove_file_member_2");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<FileMemberRemoveActionResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<RemoveFileMemberError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::RemoveFileMemberError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Use to obtain the members who have been invited to a file, both inherited and uninherited members.
+	/// Files with FileMetadata.has_explicit_shared_members set, or FileMetadata.sharing_info for files
+	/// inside a shared folder, have members to list.
+	pub fn list_file_members(&self, arg: ListFileMembersArg)
+	-> Result<SharedFileMembers>
+	{
+		let uri = gen_uri!("sharing", "list_file_members");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFileMembers>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFileMembersError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFileMembersError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from list_file_members or list_file_members/batch,
+	/// use this to paginate through all shared file members.
+	pub fn list_file_members_continue(&self, arg: ListFileMembersContinueArg)
+	-> Result<SharedFileMembers>
+	{
+		let uri = gen_uri!("sharing", "list_file_members", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFileMembers>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFileMembersContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFileMembersContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Get members of multiple files at once. The arguments to this route are more limited,
+	/// and the limit on query result size per file is more strict. To customize the results more,
+	/// use the individual file endpoint. Inherited users and groups are not included in the result,
+	/// and permissions are not returned for this endpoint.
+	pub fn list_file_members_batch(&self, arg: ListFileMembersBatchArg)
+	-> Result<Vec<ListFileMembersBatchResult>>
+	{
+		let uri = gen_uri!("sharing", "list_file_members", "batch");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Vec<ListFileMembersBatchResult>>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<SharingUserError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::SharingUserError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Changes a member's access on a shared file.
+	pub fn update_file_member(&self, arg: UpdateFileMemberArgs)
+	-> Result<MemberAccessLevelResult>
+	{
+		let uri = gen_uri!("sharing", "update_file_member");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<MemberAccessLevelResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<FileMemberActionError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::FileMemberActionError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Returns a list of all files shared with current user. Does not include files the user
+	/// has received via shared folders, and does not include unclaimed invitations.
+	pub fn list_received_files(&self, arg: ListFilesArg)
+	-> Result<ListFilesResult>
+	{
+		let uri = gen_uri!("sharing", "list_received_files");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFilesResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<SharingUserError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::SharingUserError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Get more results with a cursor from list_received_files.
+	pub fn list_received_files_continue(&self, arg: ListFilesContinueArg)
+	-> Result<ListFilesResult>
+	{
+		let uri = gen_uri!("sharing", "list_received_files", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFilesResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFilesContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFilesContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Return the list of all shared folders the current user can mount or unmount.
+	pub fn list_mountable_folders(&self, arg: ListFoldersArgs)
+	-> Result<ListFoldersResult>
+	{
+		let uri = gen_uri!("sharing", "list_mountable_folders");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFoldersResult>(&resp)
+		{
+			Err(_) => Err(DropboxError::Other),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Once a cursor has been retrieved from list_mountable_folders, use this to paginate through all
+	/// mountable shared folders. The cursor must come from a previous call to list_mountable_folders
+	/// or list_mountable_folders/continue.
+	pub fn list_mountable_folders_continue(&self, arg: ListFoldersContinueArg)
+	-> Result<ListFoldersResult>
+	{
+		let uri = gen_uri!("sharing", "list_mountable_folders", "continue");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<ListFoldersResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<ListFoldersContinueError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::ListFoldersContinueError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// The current user mounts the designated folder. Mount a shared folder for a user after they have
+	/// been added as a member. Once mounted, the shared folder will appear in their Dropbox.
+	pub fn mount_folder(&self, arg: MountFolderArg)
+	-> Result<SharedFolderMetadata>
+	{
+		let uri = gen_uri!("sharing", "mount_folder");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<SharedFolderMetadata>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<MountFolderError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::MountFolderError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// The current user unmounts the designated folder. They can re-mount the folder at a later time using mount_folder.
+	pub fn unmount_folder(&self, arg: UnmountFolderArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("sharing", "unmount_folder");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<UnmountFolderError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::UnmountFolderError(r)),
+		}
+	}
+
+	/// The current user relinquishes their membership in the designated shared folder and will no longer
+	/// have access to the folder. A folder owner cannot relinquish membership in their own folder.
+	/// This will run synchronously if leave_a_copy is false, and asynchronously if leave_a_copy is true.
+	/// Poll an asynchronous job with check_job_status.
+	pub fn relinquish_folder_membership(&self, arg: RelinquishFolderMembershipArg)
+	-> Result<LaunchEmptyResult>
+	{
+		let uri = gen_uri!("sharing", "relinquish_folder_membership");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<LaunchEmptyResult>(&resp)
+		{
+			Err(_) => Err(match serde_json::from_str::<Error<RelinquishFolderMembershipError>>(&resp)
+			{
+				Err(_) => DropboxError::Other,
+				Ok(r) => DropboxError::RelinquishFolderMembershipError(r),
+			}),
+			Ok(r) => Ok(r),
+		}
+	}
+
+	/// Transfer ownership of a shared folder to a member of the shared folder.
+	/// User must have AccessLevel.owner access to the shared folder to perform a transfer.
+	pub fn transfer_folder(&self, arg: TransferFolderArg)
+	-> Result<()>
+	{
+		let uri = gen_uri!("sharing", "transfer_folder");
+		let body: String = serde_json::to_string(&arg)?;
+		let resp: String = self.dropbox.send_request(&uri, &body)?;
+		match serde_json::from_str::<Error<TransferFolderError>>(&resp)
+		{
+			Err(e) => match e.is_eof() || resp.trim() == "null"
+			{
+				false => Err(DropboxError::Other),
+				true => Ok(()),
+			},
+			Ok(r) => Err(DropboxError::TransferFolderError(r)),
+		}
+	}
+
+	/// Lists every member with access to a shared folder or shared file below `path`.
+	/// Shared folders are found through the sharing info of the listed entries, so a shared folder
+	/// containing `path` is reported at `path` itself. Display names of users are resolved
+	/// with DropboxUsers::get_account_batch.
+	pub fn access_report(&self, path: &str)
+	-> Result<AccessReport>
+	{
+		let mut folders = BTreeMap::new();
+		let mut files = BTreeMap::new();
+		for entry in DropboxFiles::new(self.dropbox).list_folder_iter(ListFolderArg
+		{
+			path: path.to_owned(),
+			recursive: true,
+			include_media_info: false,
+			include_deleted: false,
+			include_has_explicit_shared_members: true,
+			shared_link: None,
+		})?
+		{
+			match entry?
+			{
+				Metadata::Folder(folder) =>
+				{
+					let display = folder.path_display.unwrap_or_default();
+					if let Some(info) = folder.sharing_info
+					{
+						if let Some(id) = info.shared_folder_id
+						{
+							folders.insert(id, display);
+						}
+						else if let Some(id) = info.parent_shared_folder_id
+						{
+							folders.entry(id).or_insert_with(|| path.to_owned());
+						}
+					}
+				},
+				Metadata::File(file) =>
+				{
+					let display = file.path_display.unwrap_or_default();
+					if let Some(info) = file.sharing_info
+					{
+						folders.entry(info.parent_shared_folder_id).or_insert_with(|| path.to_owned());
+					}
+					if file.has_explicit_shared_members == Some(true)
+					{
+						files.insert(file.id, display);
+					}
+				},
+				Metadata::Deleted(_) => {},
+			}
+		}
+
+		let mut rows = vec![];
+		for (id, folder) in folders
+		{
+			let mut page = self.list_folder_members(ListFolderMembersArgs
+			{
+				shared_folder_id: id,
+				..ListFolderMembersArgs::default()
+			})?;
+			loop
+			{
+				push_members(&mut rows, &folder, page.users, page.groups, page.invitees);
+				page = match page.cursor
+				{
+					None => break,
+					Some(cursor) => self.list_folder_members_continue(ListFolderMembersContinueArg
+					{
+						cursor: cursor,
+					})?,
+				};
+			}
+		}
+		for (id, file) in files
+		{
+			let mut page = self.list_file_members(ListFileMembersArg
+			{
+				file: id,
+				include_inherited: false,
+				..ListFileMembersArg::default()
+			})?;
+			loop
+			{
+				push_members(&mut rows, &file, page.users, page.groups, page.invitees);
+				page = match page.cursor
+				{
+					None => break,
+					Some(cursor) => self.list_file_members_continue(ListFileMembersContinueArg
+					{
+						cursor: cursor,
+					})?,
+				};
+			}
+		}
+
+		self.resolve_names(&mut rows)?;
+		rows.sort_by(|a, b| a.path.cmp(&b.path));
+		Ok(AccessReport
+		{
+			path: path.to_owned(),
+			rows: rows,
+		})
+	}
+
+	/// Replaces the names of user members by the display names of their accounts.
+	/// Accounts which can't be looked up keep the name reported by the member list.
+	fn resolve_names(&self, rows: &mut [AccessReportRow])
+	-> Result<()>
+	{
+		let mut ids = BTreeSet::new();
+		for row in rows.iter()
+		{
+			if let AccessMember::User { ref account_id, .. } = row.member
+			{
+				ids.insert(account_id.clone());
+			}
+		}
+		let ids: Vec<String> = ids.into_iter().collect();
+		let users = DropboxUsers::new(self.dropbox);
+		let names = display_names(&ids, |batch| users.get_account_batch(GetAccountBatchArg { account_ids: batch }))?;
+		for row in rows.iter_mut()
+		{
+			if let AccessMember::User { ref account_id, ref mut name, .. } = row.member
+			{
+				if let Some(display_name) = names.get(account_id)
+				{
+					*name = display_name.clone();
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Looks up the display names of the accounts in batches of 300 with get_batch.
+/// An account which doesn't exist is dropped from its batch, which is then looked up again.
+/// If a batch fails for another reason, its accounts are looked up one by one.
+pub(crate) fn display_names<F>(ids: &[String], get_batch: F)
+-> Result<BTreeMap<String, String>>
+	where F: Fn(Vec<String>) -> Result<Vec<BasicAccount>>
+{
+	let mut names = BTreeMap::new();
+	let mut insert = |accounts: Vec<BasicAccount>| for account in accounts
+	{
+		names.insert(account.account_id, account.name.display_name);
+	};
+	for batch in ids.chunks(300)
+	{
+		let mut batch = batch.to_vec();
+		while !batch.is_empty()
+		{
+			let err = match get_batch(batch.clone())
+			{
+				Err(DropboxError::GetAccountBatchError(err)) => err,
+				Err(e) => return Err(e),
+				Ok(accounts) =>
+				{
+					insert(accounts);
+					break;
+				},
+			};
+			match err.error
+			{
+				GetAccountBatchError::NoAccount { ref no_account } if batch.contains(no_account) =>
+				{
+					batch.retain(|id| id != no_account);
+				},
+				_ =>
+				{
+					for id in batch.drain(..)
+					{
+						match get_batch(vec![id])
+						{
+							Err(DropboxError::GetAccountBatchError(_)) => {},
+							Err(e) => return Err(e),
+							Ok(accounts) => insert(accounts),
+						}
+					}
+				},
+			}
+		}
+	}
+	Ok(names)
+}
+
+fn push_members(rows: &mut Vec<AccessReportRow>, path: &str, users: Vec<UserMembershipInfo>,
+	groups: Vec<GroupMembershipInfo>, invitees: Vec<InviteeMembershipInfo>)
+{
+	for user in users
+	{
+		rows.push(AccessReportRow
+		{
+			path: path.to_owned(),
+			member: AccessMember::User
+			{
+				account_id: user.user.account_id,
+				name: user.user.display_name,
+				email: user.user.email,
+			},
+			access_level: user.access_type,
+			is_inherited: user.is_inherited,
+		});
+	}
+	for group in groups
+	{
+		rows.push(AccessReportRow
+		{
+			path: path.to_owned(),
+			member: AccessMember::Group
+			{
+				group_id: group.group.group_id,
+				group_name: group.group.group_name,
+			},
+			access_level: group.access_type,
+			is_inherited: group.is_inherited,
+		});
+	}
+	for invitee in invitees
+	{
+		let email = match invitee.invitee
+		{
+			InviteeInfo::Email { email } => email,
+			InviteeInfo::Other => continue,
+		};
+		rows.push(AccessReportRow
+		{
+			path: path.to_owned(),
+			member: AccessMember::Invitee { email: email },
+			access_level: invitee.access_type,
+			is_inherited: invitee.is_inherited,
+		});
+	}
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use serde_json;
+
+use ::error::*;
+use ::models::auth::*;
+
+/// Persists credentials, keyed by the Dropbox account id of the user who authorized the app.
+///
+/// Pass a store to `Dropbox::from_store` to load a credential and to save it again whenever
+/// the access token is refreshed.
+pub trait CredentialStore: Send + Sync
+{
+	/// Returns the credential of the account, or None if none was saved.
+	fn load(&self, account_id: &str)
+	-> Result<Option<Credential>>;
+
+	/// Saves the credential of the account, replacing a previously saved one.
+	fn save(&self, account_id: &str, credential: &Credential)
+	-> Result<()>;
+
+	/// Deletes the credential of the account, e.g. after its token was revoked.
+	fn delete(&self, account_id: &str)
+	-> Result<()>;
+}
+
+/// Stores all credentials as a JSON object in a single file, which is only readable by its owner.
+pub struct JsonFileStore
+{
+	path: PathBuf,
+	lock: Mutex<()>,
+}
+
+impl JsonFileStore
+{
+	pub fn new<P: Into<PathBuf>>(path: P)
+	-> JsonFileStore
+	{
+		JsonFileStore
+		{
+			path: path.into(),
+			lock: Mutex::new(()),
+		}
+	}
+
+	fn read(&self)
+	-> Result<HashMap<String, Credential>>
+	{
+		match File::open(&self.path)
+		{
+			Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(HashMap::new()),
+			Err(e) => Err(DropboxError::IoError(e)),
+			Ok(file) => Ok(serde_json::from_reader(file)?),
+		}
+	}
+
+	/// Writes the credentials into a sibling temporary file and renames it over the store,
+	/// so that a failed write never leaves a truncated store behind.
+	fn write(&self, credentials: &HashMap<String, Credential>)
+	-> Result<()>
+	{
+		let mut temp_name = self.path.file_name().map(|n| n.to_owned()).unwrap_or_default();
+		temp_name.push(".tmp");
+		let temp_path = self.path.with_file_name(temp_name);
+		let mut options = OpenOptions::new();
+		options.write(true).create(true).truncate(true);
+		set_mode(&mut options);
+		let mut file = options.open(&temp_path)?;
+		// the mode only applies to newly created files, e.g. not to one left over by a crash
+		restrict_permissions(&file)?;
+		serde_json::to_writer_pretty(&mut file, credentials)?;
+		file.sync_all()?;
+		Ok(fs::rename(&temp_path, &self.path)?)
+	}
+}
+
+impl CredentialStore for JsonFileStore
+{
+	fn load(&self, account_id: &str)
+	-> Result<Option<Credential>>
+	{
+		let _lock = self.lock.lock();
+		Ok(self.read()?.remove(account_id))
+	}
+
+	fn save(&self, account_id: &str, credential: &Credential)
+	-> Result<()>
+	{
+		let _lock = self.lock.lock();
+		let mut credentials = self.read()?;
+		credentials.insert(account_id.to_owned(), credential.clone());
+		self.write(&credentials)
+	}
+
+	fn delete(&self, account_id: &str)
+	-> Result<()>
+	{
+		let _lock = self.lock.lock();
+		let mut credentials = self.read()?;
+		if credentials.remove(account_id).is_none()
+		{
+			return Ok(());
+		}
+		if credentials.is_empty()
+		{
+			return Ok(fs::remove_file(&self.path)?);
+		}
+		self.write(&credentials)
+	}
+}
+
+#[cfg(unix)]
+fn set_mode(options: &mut OpenOptions)
+{
+	use std::os::unix::fs::OpenOptionsExt;
+	options.mode(0o600);
+}
+
+#[cfg(not(unix))]
+fn set_mode(_options: &mut OpenOptions)
+{
+}
+
+#[cfg(unix)]
+fn restrict_permissions(file: &File)
+-> Result<()>
+{
+	use std::os::unix::fs::PermissionsExt;
+	Ok(file.set_permissions(fs::Permissions::from_mode(0o600))?)
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_file: &File)
+-> Result<()>
+{
+	Ok(())
+}
+
+/// Keeps credentials in memory only, e.g. for tests.
+#[derive(Default)]
+pub struct MemoryStore
+{
+	credentials: Mutex<HashMap<String, Credential>>,
+}
+
+impl MemoryStore
+{
+	pub fn new()
+	-> MemoryStore
+	{
+		MemoryStore::default()
+	}
+
+	fn credentials<'a>(&'a self)
+	-> MutexGuard<'a, HashMap<String, Credential>>
+	{
+		match self.credentials.lock()
+		{
+			Err(e) => e.into_inner(),
+			Ok(r) => r,
+		}
+	}
+}
+
+impl CredentialStore for MemoryStore
+{
+	fn load(&self, account_id: &str)
+	-> Result<Option<Credential>>
+	{
+		Ok(self.credentials().get(account_id).cloned())
+	}
+
+	fn save(&self, account_id: &str, credential: &Credential)
+	-> Result<()>
+	{
+		self.credentials().insert(account_id.to_owned(), credential.clone());
+		Ok(())
+	}
+
+	fn delete(&self, account_id: &str)
+	-> Result<()>
+	{
+		self.credentials().remove(account_id);
+		Ok(())
+	}
+}
@@ -1,12 +1,12 @@
 #[cfg(test)]
 mod tests
 {
-	use std::io;
 	use std::io::prelude::*;
 	use std::fs::File;
 
 	use serde_json;
 	use ::models::files::*;
+	use ::models::error::*;
 
 	#[test]
 	fn files_list_folder_arg()
@@ -18,67 +18,422 @@ mod tests
 			include_media_info: false,
 			include_deleted: false,
 			include_has_explicit_shared_members: false,
+			shared_link: None,
 		};
-		let mut file = File::open("tests_json/files/list_folder_arg.json").unwrap();
-		let file: ListFolderArg = serde_json::from_reader(file).unwrap();
-		assert!(arg == file)
+		let mut file = String::new();
+		File::open("tests_json/files/list_folder_arg.json").unwrap().read_to_string(&mut file).unwrap();
+		let file: serde_json::Value = serde_json::from_str(&file).unwrap();
+		assert!(serde_json::to_value(&arg).unwrap() == file)
 	}
 
-	// TODO
 	#[test]
 	fn files_list_folder_ret()
 	{
-		let arg = ListFolderResult
+		let property_groups = vec![PropertyGroup
+		{
+			template_id: "ptid:1a5n2i6d3OYEAAAAAAAAAYa".to_string(),
+			fields: vec![PropertyField
+			{
+				name: "Security Policy".to_string(),
+				value: "Confidential".to_string(),
+			}],
+		}];
+		let ret = ListFolderResult
 		{
 			entries: vec![
-				File(FileMetadata {
+				Metadata::File(FileMetadata
+				{
 					name: "Prime_Numbers.txt".to_string(),
 					id: "id:a4ayc_80_OEAAAAAAAAAXw".to_string(),
 					client_modified: "2015-05-12T15:50:38Z".to_string(),
 					server_modified: "2015-05-12T15:50:38Z".to_string(),
 					rev: "a1c10ce0dd78".to_string(),
 					size: 7212,
-					path_lower: "/homework/math/prime_numbers.txt".to_string(),
-					path_display: "/Homework/math/Prime_Numbers.txt".to_string(),
-					sharing_info: {
+					path_lower: Some("/homework/math/prime_numbers.txt".to_string()),
+					path_display: Some("/Homework/math/Prime_Numbers.txt".to_string()),
+					sharing_info: Some(FileSharingInfo
+					{
 						read_only: true,
-						parent_shared_folder_id: 84528192421,
-						modified_by: "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc".to_string(),
-					},
-					property_groups: vec![{
-						template_id: "ptid:1a5n2i6d3OYEAAAAAAAAAYa".to_string(),
-						fields: vec![{
-							name: "Security Policy".to_string(),
-							value: "Confidential".to_string(),
-						}]
-					}],
-					has_explicit_shared_members: false,
-					content_hash: "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string(),
+						parent_shared_folder_id: "84528192421".to_string(),
+						modified_by: Some("dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc".to_string()),
+					}),
+					property_groups: Some(property_groups.clone()),
+					has_explicit_shared_members: Some(false),
+					content_hash: Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string()),
+					..FileMetadata::default()
 				}),
-				Folder(FolderMetaData {
+				Metadata::Folder(FolderMetadata
+				{
 					name: "math".to_string(),
 					id: "id:a4ayc_80_OEAAAAAAAAAXz".to_string(),
-					path_lower: "/homework/math".to_string(),
-					path_display: "/Homework/math".to_string(),
-					sharing_info: {
+					path_lower: Some("/homework/math".to_string()),
+					path_display: Some("/Homework/math".to_string()),
+					sharing_info: Some(FolderSharingInfo
+					{
 						read_only: false,
-						parent_shared_folder_id: 84528192421,
+						parent_shared_folder_id: Some("84528192421".to_string()),
+						shared_folder_id: None,
 						traverse_only: false,
 						no_access: false,
-					},
-					property_groups: [{
-						template_id: "ptid:1a5n2i6d3OYEAAAAAAAAAYa".to_string(),
-						fields: [{
-							name: "Security Policy".to_string(),
-							value: "Confidential".to_string(),
-						}]
-					}]
+					}),
+					property_groups: Some(property_groups),
+					..FolderMetadata::default()
 				})],
 			cursor: "ZtkX9_EHj3x7PMkVuFIhwKYXEpwpLwyxp9vMKomUhllil9q7eWiAu".to_string(),
-			has_more: false
+			has_more: false,
+		};
+		let file = File::open("tests_json/files/list_folder_result.json").unwrap();
+		let file: ListFolderResult = serde_json::from_reader(file).unwrap();
+		assert!(ret == file)
+	}
+
+	#[test]
+	fn file_requests_file_request()
+	{
+		use ::models::file_requests::*;
+
+		let file = File::open("tests_json/file_requests/file_request.json").unwrap();
+		let file: FileRequest = serde_json::from_reader(file).unwrap();
+		assert!(file.is_open && file.file_count == 3 && file.description.is_none());
+		assert!(file.deadline == Some(FileRequestDeadline
+		{
+			deadline: "2020-10-12T17:00:00Z".to_string(),
+			allow_late_uploads: Some(GracePeriod::SevenDays),
+		}));
+		let deadline = UpdateFileRequestDeadline::Update { deadline: None, allow_late_uploads: None };
+		assert!(serde_json::to_string(&deadline).unwrap() == r#"{".tag":"update"}"#);
+		let grace_period: GracePeriod = serde_json::from_str(r#"{".tag":"fourteen_days"}"#).unwrap();
+		assert!(grace_period == GracePeriod::Other && serde_json::to_string(&grace_period).is_err());
+	}
+
+	#[test]
+	fn files_list_revisions_error()
+	{
+		let file = File::open("tests_json/files/list_revisions_error.json").unwrap();
+		let file: Error<ListRevisionsError> = serde_json::from_reader(file).unwrap();
+		assert!(file.error == ListRevisionsError::Path { path: LookupError::NotFile })
+	}
+
+	#[test]
+	fn files_get_tags_result()
+	{
+		let ret = GetTagsResult
+		{
+			paths_to_tags: vec![PathToTags
+			{
+				path: "/homework/math/prime_numbers.txt".to_string(),
+				tags: vec![Tag::UserGeneratedTag(UserGeneratedTag
+				{
+					tag_text: "my_tag".to_string(),
+				})],
+			}],
+		};
+		let file = File::open("tests_json/files/get_tags_result.json").unwrap();
+		let file: GetTagsResult = serde_json::from_reader(file).unwrap();
+		assert!(ret == file);
+		let tag: Tag = serde_json::from_str(r#"{".tag": "system_generated_tag", "tag_text": "photos"}"#).unwrap();
+		assert!(tag == Tag::Other)
+	}
+
+	#[test]
+	fn files_add_tag_error()
+	{
+		let file = File::open("tests_json/files/add_tag_error.json").unwrap();
+		let file: Error<AddTagError> = serde_json::from_reader(file).unwrap();
+		assert!(file.error == AddTagError::Path { path: LookupError::NotFound });
+		let file: Error<AddTagError> = serde_json::from_str(
+			r#"{"error_summary": "too_many_tags/..", "error": {".tag": "too_many_tags"}}"#).unwrap();
+		assert!(file.error == AddTagError::TooManyTags)
+	}
+
+	#[test]
+	fn files_get_metadata_error()
+	{
+		let file: Error<GetMetadataError> = serde_json::from_str(r#"{"error_summary": "path/not_found/..",
+			"error": {".tag": "path", "path": {".tag": "not_found"}}}"#).unwrap();
+		assert!(file.error == GetMetadataError::Path { path: LookupError::NotFound })
+	}
+
+	#[test]
+	fn files_properties_search_error()
+	{
+		let file: Error<PropertiesSearchError> = serde_json::from_str(r#"{"error_summary": "property_group_lookup/..",
+			"error": {".tag": "property_group_lookup", "property_group_lookup": {".tag": "property_group_not_found"}}}"#).unwrap();
+		assert!(file.error == PropertiesSearchError::PropertyGroupLookup
+		{
+			property_group_lookup: LookUpPropertiesError::PropertyGroupNotFound,
+		})
+	}
+
+	#[test]
+	fn files_remove_tag_error()
+	{
+		let file = File::open("tests_json/files/remove_tag_error.json").unwrap();
+		let file: Error<RemoveTagError> = serde_json::from_reader(file).unwrap();
+		assert!(file.error == RemoveTagError::TagNotPresent);
+		let file: Error<BaseTagError> = serde_json::from_str(
+			r#"{"error_summary": "path/not_found/..", "error": {".tag": "path", "path": {".tag": "not_found"}}}"#).unwrap();
+		assert!(file.error == BaseTagError::Path { path: LookupError::NotFound })
+	}
+
+	#[test]
+	fn auth_error_missing_scope()
+	{
+		let err = Error
+		{
+			error_summary: "missing_scope/...".to_string(),
+			error: AuthError::MissingScope(TokenScopeError
+			{
+				required_scope: "files.content.read".to_string(),
+			}),
+		};
+		let file = File::open("tests_json/auth/auth_error_missing_scope.json").unwrap();
+		let file: Error<AuthError> = serde_json::from_reader(file).unwrap();
+		assert!(err == file)
+	}
+
+	#[test]
+	fn auth_token_from_oauth1_error()
+	{
+		let err = Error
+		{
+			error_summary: "app_id_mismatch/...".to_string(),
+			error: TokenFromOAuth1Error::AppIdMismatch,
+		};
+		let file = File::open("tests_json/auth/token_from_oauth1_error.json").unwrap();
+		let file: Error<TokenFromOAuth1Error> = serde_json::from_reader(file).unwrap();
+		assert!(err == file)
+	}
+
+	#[test]
+	fn common_path_root()
+	{
+		use ::models::common::PathRoot;
+
+		let root = PathRoot::Root { root: "3235641".to_string() };
+		assert!(serde_json::to_string(&root).unwrap() == r#"{".tag":"root","root":"3235641"}"#);
+		assert!(serde_json::to_string(&PathRoot::Home).unwrap() == r#"{".tag":"home"}"#);
+	}
+
+	#[test]
+	fn common_path_root_error()
+	{
+		use ::models::common::RootInfo;
+
+		let file = File::open("tests_json/auth/path_root_error.json").unwrap();
+		let file: Error<PathRootError> = serde_json::from_reader(file).unwrap();
+		match file.error
+		{
+			PathRootError::InvalidRoot { invalid_root: RootInfo::Team(ref r) } =>
+				assert!(r.root_namespace_id == "3235641" && r.home_path == "/Franz Ferdinand"),
+			_ => panic!("expected invalid_root"),
+		}
+	}
+
+	#[test]
+	fn sharing_list_shared_links_result()
+	{
+		use ::models::sharing::*;
+
+		let file = File::open("tests_json/sharing/list_shared_links_result.json").unwrap();
+		let file: ListSharedLinksResult = serde_json::from_reader(file).unwrap();
+		assert!(file.has_more && file.links.len() == 1);
+		assert!(file.links[0].path_lower() == Some("/homework/math/prime_numbers.txt"));
+		assert!(file.links[0].link_permissions().resolved_visibility == Some(ResolvedVisibility::Public));
+	}
+
+	#[test]
+	fn sharing_link_exposure()
+	{
+		use ::models::sharing::*;
+		use ::models::users::SharedLinkCreatePolicy;
+
+		let file = File::open("tests_json/sharing/list_shared_links_result.json").unwrap();
+		let file: ListSharedLinksResult = serde_json::from_reader(file).unwrap();
+		let mut link = file.links[0].clone();
+		let policy = SharedLinkCreatePolicy::TeamOnly;
+		assert!(LinkExposure::classify(&link, Some(&policy), "2015-05-12T15:50:38Z") == LinkExposure::Public);
+		if let SharedLinkMetadata::File(ref mut r) = link
+		{
+			r.link_permissions.resolved_visibility = None;
+			r.expires = Some("2016-01-01T00:00:00Z".to_string());
+		}
+		assert!(LinkExposure::classify(&link, Some(&policy), "2015-05-12T15:50:38Z") == LinkExposure::TeamOnly);
+		let default_team_only = SharedLinkCreatePolicy::DefaultTeamOnly;
+		assert!(LinkExposure::classify(&link, Some(&default_team_only), "2015-05-12T15:50:38Z") == LinkExposure::Public);
+		assert!(LinkExposure::classify(&link, None, "2017-01-01T00:00:00Z") == LinkExposure::Expired);
+		if let SharedLinkMetadata::File(ref mut r) = link
+		{
+			r.link_permissions.requested_visibility = Some(RequestedVisibility::Password);
+		}
+		assert!(LinkExposure::classify(&link, Some(&policy), "2015-05-12T15:50:38Z") == LinkExposure::Password);
+		assert!(::format_timestamp(1431445838) == "2015-05-12T15:50:38Z");
+	}
+
+	#[test]
+	fn sharing_link_exposure_effective_audience()
+	{
+		use ::models::sharing::*;
+		use ::models::users::SharedLinkCreatePolicy;
+
+		let file = File::open("tests_json/sharing/shared_link_metadata.json").unwrap();
+		let mut link: SharedLinkMetadata = serde_json::from_reader(file).unwrap();
+		let policy = SharedLinkCreatePolicy::DefaultPublic;
+		assert!(LinkExposure::classify(&link, Some(&policy), "2015-05-12T15:50:38Z") == LinkExposure::TeamOnly);
+		if let SharedLinkMetadata::Folder(ref mut r) = link
+		{
+			r.link_permissions.effective_audience = Some(LinkAudience::Members);
+		}
+		assert!(LinkExposure::classify(&link, None, "2015-05-12T15:50:38Z") == LinkExposure::Restricted);
+		if let SharedLinkMetadata::Folder(ref mut r) = link
+		{
+			r.link_permissions.effective_audience = Some(LinkAudience::Public);
+			r.link_permissions.requested_visibility = Some(RequestedVisibility::Password);
+		}
+		assert!(LinkExposure::classify(&link, None, "2015-05-12T15:50:38Z") == LinkExposure::Password);
+	}
+
+	#[test]
+	fn sharing_display_names()
+	{
+		use std::cell::Cell;
+		use ::error::DropboxError;
+		use ::models::users::{BasicAccount, Name};
+
+		let file = File::open("tests_json/users/get_account_batch_error.json").unwrap();
+		let err: Error<GetAccountBatchError> = serde_json::from_reader(file).unwrap();
+		let missing = "dbid:AAH1Vcz-DVoRDeixtr_OA8oUGgiqhs4XPOQ".to_string();
+		assert!(err.error == GetAccountBatchError::NoAccount { no_account: missing.clone() });
+		let ids = vec!["dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc".to_string(), missing.clone()];
+		let calls = Cell::new(0);
+		let names = ::sharing::display_names(&ids, |batch|
+		{
+			calls.set(calls.get() + 1);
+			if batch.contains(&missing)
+			{
+				return Err(DropboxError::GetAccountBatchError(err.clone()));
+			}
+			Ok(batch.into_iter().map(|id| BasicAccount
+			{
+				account_id: id,
+				name: Name { display_name: "Franz Ferdinand (Personal)".to_string(), ..Name::default() },
+				..BasicAccount::default()
+			}).collect())
+		}).unwrap();
+		assert!(calls.get() == 2 && names.len() == 1);
+		assert!(names[&ids[0]] == "Franz Ferdinand (Personal)");
+	}
+
+	#[test]
+	fn sharing_share_folder_job_status()
+	{
+		use ::models::sharing::*;
+		use ::models::users::SharedFolderMemberPolicy;
+
+		let file = File::open("tests_json/sharing/share_folder_job_status.json").unwrap();
+		let file: ShareFolderJobStatus = serde_json::from_reader(file).unwrap();
+		match file
+		{
+			ShareFolderJobStatus::Complete(ref r) =>
+			{
+				assert!(r.access_type == AccessLevel::Owner && r.shared_folder_id == "84528192421");
+				assert!(r.policy.resolved_member_policy == Some(SharedFolderMemberPolicy::Team));
+			},
+			_ => panic!("expected complete"),
+		}
+	}
+
+	#[test]
+	fn paper_docs_list_args()
+	{
+		use ::models::paper::*;
+
+		let arg = ListPaperDocsArgs
+		{
+			sort_order: ListPaperDocsSortOrder::Descending,
+			..ListPaperDocsArgs::default()
+		};
+		let mut expected = String::new();
+		File::open("tests_json/paper/list_paper_docs_args.json").unwrap().read_to_string(&mut expected).unwrap();
+		let expected: serde_json::Value = serde_json::from_str(&expected).unwrap();
+		assert!(serde_json::to_value(&arg).unwrap() == expected)
+	}
+
+	#[test]
+	fn paper_folders_containing_paper_doc()
+	{
+		use ::models::paper::*;
+
+		let file = File::open("tests_json/paper/get_folder_info_result.json").unwrap();
+		let file: FoldersContainingPaperDoc = serde_json::from_reader(file).unwrap();
+		assert!(file.folder_sharing_policy_type == Some(FolderSharingPolicyType::Team));
+		assert!(file.folders.unwrap()[0].name == "Design docs");
+	}
+
+	#[test]
+	fn paper_list_users_on_paper_doc_response()
+	{
+		use ::models::paper::*;
+		use ::models::sharing::InviteeInfo;
+
+		let file = File::open("tests_json/paper/list_users_on_paper_doc_response.json").unwrap();
+		let file: ListUsersOnPaperDocResponse = serde_json::from_reader(file).unwrap();
+		assert!(file.users[0].permission_level == PaperDocPermissionLevel::Edit);
+		assert!(file.invitees[0].invitee == InviteeInfo::Email { email: "jessica@example.com".to_string() });
+		assert!(file.doc_owner.display_name == "Robert Smith" && !file.has_more);
+	}
+
+	#[test]
+	fn secret_redaction()
+	{
+		use ::models::auth::TokenResponse;
+
+		let file = File::open("tests_json/auth/from_auth.json").unwrap();
+		let token: TokenResponse = serde_json::from_reader(file).unwrap();
+		assert!(token.access_token.expose() == "ABCDEFG");
+		assert!(!format!("{:?}", token).contains("ABCDEFG"));
+		let body = ::secret::redact_body(r#"{"access_token": "ABCDEFG", "token_type": "bearer"}"#);
+		assert!(!body.contains("ABCDEFG") && body.contains("bearer"));
+		let body = ::secret::redact_body(r#"{"url": "https://www.dropbox.com/s/2sn712vy1ovegw8", "link_password": "HIJKLMN"}"#);
+		assert!(!body.contains("HIJKLMN") && body.contains("2sn712vy1ovegw8"));
+		let mut headers = ::hyper::header::Headers::new();
+		headers.set_raw("Dropbox-API-Arg", vec![br#"{"url": "https://www.dropbox.com/s/2sn712vy1ovegw8", "link_password": "HIJKLMN"}"#.to_vec()]);
+		let headers = ::secret::redact_headers(&headers);
+		assert!(!headers.contains("HIJKLMN") && headers.contains("2sn712vy1ovegw8"));
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn store_json_file_store()
+	{
+		use std::env;
+		use std::os::unix::fs::PermissionsExt;
+		use std::process;
+		use ::models::auth::Credential;
+		use ::store::{CredentialStore, JsonFileStore};
+
+		let path = env::temp_dir().join(format!("dropbox-rs-store_json_file_store-{}.json", process::id()));
+		let store = JsonFileStore::new(path.clone());
+		let credential = Credential
+		{
+			account_id: "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc".to_string(),
+			access_token: "ABCDEFG".into(),
+			..Credential::default()
 		};
-		let mut file = File::open("tests_json/files/list_folder_arg.json").unwrap();
-		let file: ListFolderArg = serde_json::from_reader(file).unwrap();
-		assert!(arg == file)
+		store.save(&credential.account_id, &credential).unwrap();
+		let mode = ::std::fs::metadata(&path).unwrap().permissions().mode();
+		assert!(mode & 0o777 == 0o600);
+		let loaded = store.load(&credential.account_id).unwrap().unwrap();
+		assert!(loaded.access_token == credential.access_token);
+		store.delete(&credential.account_id).unwrap();
+		assert!(store.load(&credential.account_id).unwrap().is_none());
+	}
+
+	#[test]
+	fn oauth2_pkce_code_challenge()
+	{
+		// example from RFC 7636, appendix B
+		let challenge = ::oauth2::code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+		assert!(challenge == "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM")
 	}
 }
@@ -48,10 +48,10 @@ impl<'a> DropboxUsers<'a>
 		let resp: String = self.dropbox.send_request(&uri, &body)?;
 		match serde_json::from_str::<Vec<BasicAccount>>(&resp)
 		{
-			Err(_) => Err(match serde_json::from_str::<Error<GetAccountError>>(&resp)
+			Err(_) => Err(match serde_json::from_str::<Error<GetAccountBatchError>>(&resp)
 			{
 				Err(_) => DropboxError::Other,
-				Ok(r) => DropboxError::GetAccountError(r),
+				Ok(r) => DropboxError::GetAccountBatchError(r),
 			}),
 			Ok(r) => Ok(r),
 		}